@@ -0,0 +1,115 @@
+//! Timing of the parse and solve phases of a solution
+//!
+//! The runner uses these functions for its `bench` command. Each phase is run a given number of
+//! times and summarized by its minimum, median and maximum wall time.
+
+use core::fmt;
+use core::hint::black_box;
+use core::time::Duration;
+use std::time::Instant;
+
+use crate::solution::{InputKind, Part, Solution};
+
+/// Summary of the wall times of multiple runs of a phase
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Timings {
+    /// Number of runs
+    pub iterations: usize,
+    /// Slowest run
+    pub max: Duration,
+    /// Median run (the upper one for an even number of runs)
+    pub median: Duration,
+    /// Fastest run
+    pub min: Duration,
+}
+
+impl Timings {
+    /// Summarizes the wall times of the given runs, returns `None` if there are no runs
+    #[must_use]
+    pub fn from_samples(mut samples: Vec<Duration>) -> Option<Self> {
+        samples.sort_unstable();
+        Some(Self {
+            iterations: samples.len(),
+            max: *samples.last()?,
+            median: *samples.get(samples.len().div_euclid(2))?,
+            min: *samples.first()?,
+        })
+    }
+}
+
+impl fmt::Display for Timings {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let min = format_duration(self.min);
+        let median = format_duration(self.median);
+        let max = format_duration(self.max);
+        let iterations = self.iterations;
+        write!(
+            f,
+            "min {min:>11}, median {median:>11}, max {max:>11} ({iterations} runs)"
+        )
+    }
+}
+
+/// Formats a duration with three decimal places in the largest unit that fits
+fn format_duration(duration: Duration) -> String {
+    let nanos = duration.as_nanos();
+    let (scale, unit) = match nanos {
+        0..1_000 => return format!("{nanos} ns"),
+        1_000..1_000_000 => (1_000, "us"),
+        1_000_000..1_000_000_000 => (1_000_000, "ms"),
+        _ => (1_000_000_000, "s"),
+    };
+    let whole = nanos.div_euclid(scale);
+    let fraction = nanos.rem_euclid(scale).div_euclid(scale.div_euclid(1_000));
+    format!("{whole}.{fraction:03} {unit}")
+}
+
+/// Runs `phase` `iterations` times and measures the wall time of each run
+///
+/// # Errors
+///
+/// Returns the error of the first failing run or an error if `iterations` is zero.
+pub fn measure<T, F: FnMut() -> Result<T, String>>(
+    iterations: usize,
+    mut phase: F,
+) -> Result<Timings, String> {
+    let mut samples = Vec::with_capacity(iterations);
+    for _ in 0..iterations {
+        let start = Instant::now();
+        black_box(phase()?);
+        samples.push(start.elapsed());
+    }
+    Timings::from_samples(samples).ok_or_else(|| "Need at least one iteration!".to_owned())
+}
+
+/// Measures how long the solution `S` takes to parse `content` of the given kind
+///
+/// # Errors
+///
+/// Returns an error message if the input cannot be parsed.
+pub fn time_parse<S: Solution>(
+    content: &str,
+    kind: InputKind,
+    iterations: usize,
+) -> Result<Timings, String> {
+    measure(iterations, || {
+        S::parse_input_of_kind(black_box(content), kind).map_err(|err| err.to_string())
+    })
+}
+
+/// Measures how long the solution `S` takes to solve `part` for `content`
+///
+/// The input is parsed only once before the measurement.
+///
+/// # Errors
+///
+/// Returns an error message if the input cannot be parsed or the part cannot be solved.
+pub fn time_part<S: Solution>(
+    part: Part,
+    content: &str,
+    kind: InputKind,
+    iterations: usize,
+) -> Result<Timings, String> {
+    let input = S::parse_input_of_kind(content, kind).map_err(|err| err.to_string())?;
+    measure(iterations, || S::solve_part(part, black_box(&input)))
+}
@@ -0,0 +1,311 @@
+//! Points and vectors with two or three coordinates
+//!
+//! Points are generic over their [`Coordinate`] type, which is usually `i64` for tiles and
+//! positions and `f64` for measurements. Negative coordinates are allowed everywhere. All
+//! arithmetic is checked and returns `None` instead of overflowing or becoming infinite.
+
+use core::fmt;
+use core::str::FromStr;
+
+use crate::input::{ParseError, parse_token};
+
+/// Number type that can be used as the coordinate of a point
+pub trait Coordinate: Copy + PartialOrd + FromStr + fmt::Display {
+    /// The additive identity
+    const ZERO: Self;
+
+    /// Absolute value, or `None` if it cannot be represented
+    fn checked_abs(self) -> Option<Self>;
+
+    /// Absolute difference of two values, or `None` if it cannot be represented
+    fn checked_abs_diff(self, other: Self) -> Option<Self> {
+        self.checked_sub(other)?.checked_abs()
+    }
+
+    /// Sum of two values, or `None` if it cannot be represented
+    fn checked_add(self, other: Self) -> Option<Self>;
+
+    /// Product of two values, or `None` if it cannot be represented
+    fn checked_mul(self, other: Self) -> Option<Self>;
+
+    /// Difference of two values, or `None` if it cannot be represented
+    fn checked_sub(self, other: Self) -> Option<Self>;
+}
+
+/// Point or vector in the plane
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point2<T> {
+    /// Coordinate on the x axis
+    pub x: T,
+    /// Coordinate on the y axis
+    pub y: T,
+}
+
+/// Point or vector in space
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Point3<T> {
+    /// Coordinate on the x axis
+    pub x: T,
+    /// Coordinate on the y axis
+    pub y: T,
+    /// Coordinate on the z axis
+    pub z: T,
+}
+
+impl Coordinate for i64 {
+    const ZERO: Self = 0;
+
+    fn checked_abs(self) -> Option<Self> {
+        Self::checked_abs(self)
+    }
+
+    fn checked_add(self, other: Self) -> Option<Self> {
+        Self::checked_add(self, other)
+    }
+
+    fn checked_mul(self, other: Self) -> Option<Self> {
+        Self::checked_mul(self, other)
+    }
+
+    fn checked_sub(self, other: Self) -> Option<Self> {
+        Self::checked_sub(self, other)
+    }
+}
+
+#[expect(
+    clippy::float_arithmetic,
+    reason = "The results are checked for being finite."
+)]
+impl Coordinate for f64 {
+    const ZERO: Self = 0.0;
+
+    fn checked_abs(self) -> Option<Self> {
+        finite(self.abs())
+    }
+
+    fn checked_add(self, other: Self) -> Option<Self> {
+        finite(self + other)
+    }
+
+    fn checked_mul(self, other: Self) -> Option<Self> {
+        finite(self * other)
+    }
+
+    fn checked_sub(self, other: Self) -> Option<Self> {
+        finite(self - other)
+    }
+}
+
+impl<T: Coordinate> Point2<T> {
+    /// Sum of two vectors
+    #[must_use]
+    pub fn checked_add(&self, other: &Self) -> Option<Self> {
+        Some(Self::new(
+            self.x.checked_add(other.x)?,
+            self.y.checked_add(other.y)?,
+        ))
+    }
+
+    /// Multiplies both coordinates with `factor`
+    #[must_use]
+    pub fn checked_scale(&self, factor: T) -> Option<Self> {
+        Some(Self::new(
+            self.x.checked_mul(factor)?,
+            self.y.checked_mul(factor)?,
+        ))
+    }
+
+    /// Difference of two vectors, i.e. the vector from `other` to `self`
+    #[must_use]
+    pub fn checked_sub(&self, other: &Self) -> Option<Self> {
+        Some(Self::new(
+            self.x.checked_sub(other.x)?,
+            self.y.checked_sub(other.y)?,
+        ))
+    }
+
+    /// Cross product of two vectors, which is positive if `other` is counterclockwise of `self`
+    #[must_use]
+    pub fn cross(&self, other: &Self) -> Option<T> {
+        self.x
+            .checked_mul(other.y)?
+            .checked_sub(self.y.checked_mul(other.x)?)
+    }
+
+    /// Dot product of two vectors
+    #[must_use]
+    pub fn dot(&self, other: &Self) -> Option<T> {
+        self.x
+            .checked_mul(other.x)?
+            .checked_add(self.y.checked_mul(other.y)?)
+    }
+
+    /// Sum of the absolute differences of the coordinates
+    #[must_use]
+    pub fn manhattan_distance(&self, other: &Self) -> Option<T> {
+        self.x
+            .checked_abs_diff(other.x)?
+            .checked_add(self.y.checked_abs_diff(other.y)?)
+    }
+
+    /// Creates a point from its coordinates
+    #[must_use]
+    pub const fn new(x: T, y: T) -> Self {
+        Self { x, y }
+    }
+
+    /// Parses a point from two comma-separated coordinates, which may be surrounded by whitespace
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the line does not contain exactly two numbers.
+    pub fn parse(line_idx: usize, line: &str) -> Result<Self, ParseError> {
+        let [x, y] = parse_coordinates(line_idx, line)?;
+        Ok(Self::new(x, y))
+    }
+
+    /// Square of the euclidean distance, which is exact for integer coordinates
+    #[must_use]
+    pub fn squared_distance(&self, other: &Self) -> Option<T> {
+        let diff = self.checked_sub(other)?;
+        diff.dot(&diff)
+    }
+}
+
+impl<T: Coordinate> Point3<T> {
+    /// Sum of two vectors
+    #[must_use]
+    pub fn checked_add(&self, other: &Self) -> Option<Self> {
+        Some(Self::new(
+            self.x.checked_add(other.x)?,
+            self.y.checked_add(other.y)?,
+            self.z.checked_add(other.z)?,
+        ))
+    }
+
+    /// Multiplies all coordinates with `factor`
+    #[must_use]
+    pub fn checked_scale(&self, factor: T) -> Option<Self> {
+        Some(Self::new(
+            self.x.checked_mul(factor)?,
+            self.y.checked_mul(factor)?,
+            self.z.checked_mul(factor)?,
+        ))
+    }
+
+    /// Difference of two vectors, i.e. the vector from `other` to `self`
+    #[must_use]
+    pub fn checked_sub(&self, other: &Self) -> Option<Self> {
+        Some(Self::new(
+            self.x.checked_sub(other.x)?,
+            self.y.checked_sub(other.y)?,
+            self.z.checked_sub(other.z)?,
+        ))
+    }
+
+    /// Coordinate on the given axis, where 0 is x, 1 is y and everything else is z
+    #[must_use]
+    pub const fn coordinate(&self, axis: usize) -> T {
+        match axis {
+            0 => self.x,
+            1 => self.y,
+            _ => self.z,
+        }
+    }
+
+    /// Cross product of two vectors, which is orthogonal to both of them
+    #[must_use]
+    pub fn cross(&self, other: &Self) -> Option<Self> {
+        let component = |first: T, second: T, third: T, fourth: T| {
+            first
+                .checked_mul(second)?
+                .checked_sub(third.checked_mul(fourth)?)
+        };
+        Some(Self::new(
+            component(self.y, other.z, self.z, other.y)?,
+            component(self.z, other.x, self.x, other.z)?,
+            component(self.x, other.y, self.y, other.x)?,
+        ))
+    }
+
+    /// Dot product of two vectors
+    #[must_use]
+    pub fn dot(&self, other: &Self) -> Option<T> {
+        self.x
+            .checked_mul(other.x)?
+            .checked_add(self.y.checked_mul(other.y)?)?
+            .checked_add(self.z.checked_mul(other.z)?)
+    }
+
+    /// Sum of the absolute differences of the coordinates
+    #[must_use]
+    pub fn manhattan_distance(&self, other: &Self) -> Option<T> {
+        self.x
+            .checked_abs_diff(other.x)?
+            .checked_add(self.y.checked_abs_diff(other.y)?)?
+            .checked_add(self.z.checked_abs_diff(other.z)?)
+    }
+
+    /// Creates a point from its coordinates
+    #[must_use]
+    pub const fn new(x: T, y: T, z: T) -> Self {
+        Self { x, y, z }
+    }
+
+    /// Parses a point from three comma-separated coordinates, which may be surrounded by whitespace
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the line does not contain exactly three numbers.
+    pub fn parse(line_idx: usize, line: &str) -> Result<Self, ParseError> {
+        let [x, y, z] = parse_coordinates(line_idx, line)?;
+        Ok(Self::new(x, y, z))
+    }
+
+    /// Square of the euclidean distance, which is exact for integer coordinates
+    #[must_use]
+    pub fn squared_distance(&self, other: &Self) -> Option<T> {
+        let diff = self.checked_sub(other)?;
+        diff.dot(&diff)
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Point2<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{},{}", self.x, self.y)
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Point3<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{},{},{}", self.x, self.y, self.z)
+    }
+}
+
+/// Returns the value if it is finite
+fn finite(value: f64) -> Option<f64> {
+    value.is_finite().then_some(value)
+}
+
+/// Parses exactly `N` comma-separated coordinates, ignoring whitespace around them
+fn parse_coordinates<T: Coordinate, const N: usize>(
+    line_idx: usize,
+    line: &str,
+) -> Result<[T; N], ParseError> {
+    let numbers = line
+        .split(',')
+        .map(|token| parse_token::<T>(line_idx, line, token.trim(), "a coordinate"))
+        .collect::<Result<Vec<T>, ParseError>>()?;
+    numbers.try_into().map_err(|_numbers: Vec<T>| {
+        let count = match N {
+            2 => "two".to_owned(),
+            3 => "three".to_owned(),
+            _ => N.to_string(),
+        };
+        ParseError::at_line(
+            line_idx,
+            line,
+            &format!("{count} comma-separated coordinates"),
+        )
+    })
+}
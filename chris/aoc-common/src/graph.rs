@@ -0,0 +1,935 @@
+//! Directed graphs with named nodes
+//!
+//! Nodes are stored in an arena and referenced by their [`NodeId`], which is the order in which
+//! they were added. Names are interned, so each name maps to exactly one node.
+
+extern crate alloc;
+use alloc::collections::{BinaryHeap, VecDeque};
+use core::cmp::Reverse;
+use core::mem;
+use core::ops::Range;
+use std::collections::{HashMap, HashSet};
+
+use clap::ValueEnum;
+
+/// Maximum number of waypoints for counting paths, which tracks the subsets of them that are
+/// visited on the way to each node
+#[expect(
+    clippy::decimal_literal_representation,
+    reason = "The number of waypoints is more readable in decimal notation."
+)]
+const MAX_WAYPOINTS: u32 = 16;
+
+/// Index of a node in a [`Graph`]
+pub type NodeId = usize;
+
+/// Weight of an edge, which is 1 for unweighted edges
+pub type Weight = u64;
+
+/// Edges that a path search may not use
+type BannedEdges = HashSet<(NodeId, NodeId)>;
+
+/// Number of paths to or from a node by the subset of the waypoints that they visit
+type WaypointCounts = HashMap<usize, u128>;
+
+/// Text format for exporting a graph
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum ExportFormat {
+    /// Graphviz DOT
+    Dot,
+    /// Mermaid flowchart
+    Mermaid,
+}
+
+/// Role of a node that is highlighted in an exported graph
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Highlight {
+    /// End of the examined paths
+    End,
+    /// Start of the examined paths
+    Start,
+    /// Node that the examined paths must pass through
+    Waypoint,
+}
+
+/// Appearance of a node in an exported graph
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct NodeStyle {
+    /// Role of the node, if it should be highlighted
+    pub highlight: Option<Highlight>,
+    /// Additional text shown below the name of the node
+    pub note: Option<String>,
+}
+
+/// Directed graph with interned node names and adjacency lists in both directions
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Graph {
+    /// Node IDs by name
+    ids: HashMap<String, NodeId>,
+    /// Predecessors of each node
+    incoming: Vec<Vec<NodeId>>,
+    /// Name of each node
+    names: Vec<String>,
+    /// Successors of each node
+    outgoing: Vec<Vec<NodeId>>,
+    /// Weights of the edges to the successors of each node, in the same order as `outgoing`
+    weights: Vec<Vec<Weight>>,
+}
+
+/// Lazy iterator over the simple paths between two nodes, created by [`Graph::simple_paths`]
+#[derive(Debug, Clone)]
+pub struct SimplePaths<'graph> {
+    /// Mask with the bits of all waypoints set
+    all_waypoints: usize,
+    /// Whether the end can be reached from each node
+    can_reach_end: Vec<bool>,
+    /// Graph whose paths are enumerated
+    graph: &'graph Graph,
+    /// Whether each node is on the current path
+    on_path: Vec<bool>,
+    /// Nodes on the current path, the index of their next successor to try
+    /// and the waypoints visited up to them
+    stack: Vec<(NodeId, usize, usize)>,
+    /// Node where the paths end
+    to: NodeId,
+    /// Nodes that the paths must visit
+    via: Vec<NodeId>,
+    /// Mask of the waypoints that can be reached from each node
+    waypoints_ahead: Vec<usize>,
+}
+
+/// Path through a graph together with its total weight
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct WeightedPath {
+    /// Sum of the weights of all edges on the path
+    pub length: Weight,
+    /// Nodes on the path, starting with the first one
+    pub nodes: Vec<NodeId>,
+}
+
+impl Graph {
+    /// Topological order of the nodes, or an error naming the nodes on cycles
+    fn acyclic_order(&self) -> Result<Vec<NodeId>, String> {
+        self.topological_order().ok_or_else(|| {
+            format!(
+                "Cannot count paths in a graph with cycles! Nodes on cycles: {}",
+                self.describe_cycles()
+            )
+        })
+    }
+
+    /// Adds an edge with weight 1 from `from` to `to`, ignoring nodes that do not exist
+    pub fn add_edge(&mut self, from: NodeId, to: NodeId) {
+        self.add_weighted_edge(from, to, 1);
+    }
+
+    /// Adds an edge with the given weight from `from` to `to`, ignoring nodes that do not exist
+    pub fn add_weighted_edge(&mut self, from: NodeId, to: NodeId, weight: Weight) {
+        if from >= self.len() || to >= self.len() {
+            return;
+        }
+        if let Some(successors) = self.outgoing.get_mut(from) {
+            successors.push(to);
+        }
+        if let Some(successor_weights) = self.weights.get_mut(from) {
+            successor_weights.push(weight);
+        }
+        if let Some(predecessors) = self.incoming.get_mut(to) {
+            predecessors.push(from);
+        }
+    }
+
+    /// Lists all nodes reachable from `start` in breadth-first order, starting with `start`
+    #[must_use]
+    pub fn bfs(&self, start: NodeId) -> Vec<NodeId> {
+        self.traverse(start, Self::outgoing, false)
+    }
+
+    /// Counts the paths from `from` to `to` that visit all nodes in `via` in any order
+    ///
+    /// The paths are counted per node in topological order, so every node is processed only once
+    /// for each subset of visited waypoints instead of enumerating the paths. Only the subsets
+    /// that some path actually visits are stored.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error message if the graph contains a cycle, if there are too many waypoints
+    /// or if the number of paths does not fit into a `u128`.
+    pub fn count_paths(&self, from: NodeId, to: NodeId, via: &[NodeId]) -> Result<u128, String> {
+        let order = self.acyclic_order()?;
+        let all_waypoints = waypoint_masks(via)?.saturating_sub(1);
+        let counts = self.waypoint_counts(from, via, &order, Self::outgoing)?;
+        Ok(counts
+            .get(to)
+            .and_then(|node_counts| node_counts.get(&all_waypoints))
+            .copied()
+            .unwrap_or(0))
+    }
+
+    /// Counts for every node the paths from `from` to `to` via all nodes in `via` that pass
+    /// through it
+    ///
+    /// The paths from `from` to each node and from each node to `to` are counted once per subset
+    /// of visited waypoints, and a node multiplies the counts of the subsets that together
+    /// contain all waypoints.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error message if the graph contains a cycle, if there are too many waypoints
+    /// or if a number of paths does not fit into a `u128`.
+    pub fn count_paths_through(
+        &self,
+        from: NodeId,
+        to: NodeId,
+        via: &[NodeId],
+    ) -> Result<Vec<u128>, String> {
+        let mut order = self.acyclic_order()?;
+        let all_waypoints = waypoint_masks(via)?.saturating_sub(1);
+        let before = self.waypoint_counts(from, via, &order, Self::outgoing)?;
+        order.reverse();
+        let after = self.waypoint_counts(to, via, &order, Self::incoming)?;
+        before
+            .iter()
+            .zip(&after)
+            .map(|(to_node, from_node)| {
+                to_node
+                    .iter()
+                    .flat_map(|(&first_mask, &first)| {
+                        from_node
+                            .iter()
+                            .filter(move |&(&second_mask, _)| {
+                                first_mask | second_mask == all_waypoints
+                            })
+                            .map(move |(_, &second)| first.checked_mul(second))
+                    })
+                    .try_fold(0_u128, |sum, paths| sum.checked_add(paths?))
+                    .ok_or_else(|| "The number of paths does not fit into a u128!".to_owned())
+            })
+            .collect()
+    }
+
+    /// Counts the simple paths from `from` to `to` that visit all nodes in `via` in any order
+    ///
+    /// Unlike [`Graph::count_paths`], this also works for graphs with cycles, because no node
+    /// may appear twice on a simple path. The paths are enumerated one by one with
+    /// [`Graph::simple_paths`], so this is only feasible for small graphs or graphs with few paths.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error message if there are too many waypoints or if the number of paths
+    /// does not fit into a `u128`.
+    pub fn count_simple_paths(
+        &self,
+        from: NodeId,
+        to: NodeId,
+        via: &[NodeId],
+    ) -> Result<u128, String> {
+        let mut paths = 0_u128;
+        for _path in self.simple_paths(from, to, via)? {
+            paths = paths
+                .checked_add(1)
+                .ok_or_else(|| "The number of paths does not fit into a u128!".to_owned())?;
+        }
+        Ok(paths)
+    }
+
+    /// Lists the strongly connected components that contain a cycle
+    ///
+    /// These are the components with more than one node and the nodes with an edge to themselves.
+    #[must_use]
+    pub fn cycles(&self) -> Vec<Vec<NodeId>> {
+        self.strongly_connected_components()
+            .into_iter()
+            .filter(|component| match *component.as_slice() {
+                [node] => self.outgoing(node).contains(&node),
+                _ => true,
+            })
+            .collect()
+    }
+
+    /// Lists the names of the nodes on each cycle, e.g. `{a, b}, {c}`
+    #[must_use]
+    pub fn describe_cycles(&self) -> String {
+        self.cycles()
+            .iter()
+            .map(|component| {
+                let names = component
+                    .iter()
+                    .map(|&node| self.name(node).unwrap_or_default())
+                    .collect::<Vec<&str>>();
+                format!("{{{}}}", names.join(", "))
+            })
+            .collect::<Vec<String>>()
+            .join(", ")
+    }
+
+    /// Lists all nodes reachable from `start` in depth-first preorder, starting with `start`
+    #[must_use]
+    pub fn dfs(&self, start: NodeId) -> Vec<NodeId> {
+        self.traverse(start, Self::outgoing, true)
+    }
+
+    /// Finds the shortest path from `from` to `to` that avoids the banned nodes and edges
+    fn dijkstra(
+        &self,
+        from: NodeId,
+        to: NodeId,
+        banned_nodes: &[bool],
+        banned_edges: &BannedEdges,
+    ) -> Option<WeightedPath> {
+        let mut distances = vec![Weight::MAX; self.len()];
+        let mut predecessors = vec![None; self.len()];
+        let mut queue = BinaryHeap::from([Reverse((0, from))]);
+        *distances.get_mut(from)? = 0;
+        while let Some(Reverse((distance, node))) = queue.pop() {
+            if node == to {
+                return Some(WeightedPath {
+                    length: distance,
+                    nodes: trace_back(&predecessors, to),
+                });
+            }
+            if distances.get(node).is_some_and(|&best| distance > best) {
+                continue;
+            }
+            for (successor, weight) in self.edges(node) {
+                if banned_nodes.get(successor) == Some(&true)
+                    || banned_edges.contains(&(node, successor))
+                {
+                    continue;
+                }
+                let new_distance = distance.saturating_add(weight);
+                if let (Some(best), Some(predecessor)) = (
+                    distances.get_mut(successor),
+                    predecessors.get_mut(successor),
+                ) && new_distance < *best
+                {
+                    *best = new_distance;
+                    *predecessor = Some(node);
+                    queue.push(Reverse((new_distance, successor)));
+                }
+            }
+        }
+        None
+    }
+
+    /// Smallest weight of the edges from `from` to `to`
+    #[must_use]
+    pub fn edge_weight(&self, from: NodeId, to: NodeId) -> Option<Weight> {
+        self.edges(from)
+            .filter(|&(successor, _)| successor == to)
+            .map(|(_, weight)| weight)
+            .min()
+    }
+
+    /// Iterates over the successors of a node together with the weights of the edges to them
+    pub fn edges(&self, node: NodeId) -> impl Iterator<Item = (NodeId, Weight)> {
+        let weights = self.weights.get(node).map_or(&[][..], Vec::as_slice);
+        self.outgoing(node)
+            .iter()
+            .copied()
+            .zip(weights.iter().copied())
+    }
+
+    /// Renders the graph in the given format, styling each node with `style`
+    #[must_use]
+    pub fn export<F: Fn(NodeId) -> NodeStyle>(&self, format: ExportFormat, style: F) -> String {
+        match format {
+            ExportFormat::Dot => self.to_dot(style),
+            ExportFormat::Mermaid => self.to_mermaid(style),
+        }
+    }
+
+    /// Returns the ID of the node with the given name
+    #[must_use]
+    pub fn id(&self, name: &str) -> Option<NodeId> {
+        self.ids.get(name).copied()
+    }
+
+    /// Iterates over the IDs of all nodes
+    #[must_use]
+    pub const fn ids(&self) -> Range<NodeId> {
+        0..self.names.len()
+    }
+
+    /// Predecessors of a node, empty if the node does not exist
+    #[must_use]
+    pub fn incoming(&self, node: NodeId) -> &[NodeId] {
+        self.incoming.get(node).map_or(&[], Vec::as_slice)
+    }
+
+    /// Returns the ID of the node with the given name, adding the node if it does not exist yet
+    pub fn insert(&mut self, name: &str) -> NodeId {
+        if let Some(id) = self.id(name) {
+            return id;
+        }
+        let id = self.names.len();
+        self.ids.insert(name.to_owned(), id);
+        self.incoming.push(Vec::new());
+        self.names.push(name.to_owned());
+        self.outgoing.push(Vec::new());
+        self.weights.push(Vec::new());
+        id
+    }
+
+    /// Whether the graph has no nodes
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+
+    /// Finds up to `count` shortest simple paths from `from` to `to`, ordered by length
+    ///
+    /// Uses Yen's algorithm, which derives each path from the previous ones by deviating from
+    /// them at every node. Between two nodes, only the lightest of parallel edges is used.
+    /// A `count` of zero finds no paths.
+    #[must_use]
+    pub fn k_shortest_paths(&self, from: NodeId, to: NodeId, count: usize) -> Vec<WeightedPath> {
+        if count == 0 {
+            return Vec::new();
+        }
+        let no_nodes = vec![false; self.len()];
+        let Some(shortest) = self.dijkstra(from, to, &no_nodes, &BannedEdges::new()) else {
+            return Vec::new();
+        };
+        let mut found = vec![shortest];
+        let mut candidates: Vec<WeightedPath> = Vec::new();
+        while found.len() < count {
+            let Some(previous) = found.last() else {
+                break;
+            };
+            for (spur_idx, &spur) in previous.nodes.iter().enumerate() {
+                let Some(root) = previous.nodes.get(..=spur_idx) else {
+                    continue;
+                };
+                // forbid the edges that known paths with the same root take next
+                let banned_edges = found
+                    .iter()
+                    .filter(|path| path.nodes.starts_with(root))
+                    .filter_map(|path| Some((spur, *path.nodes.get(spur_idx.checked_add(1)?)?)))
+                    .collect::<BannedEdges>();
+                // and the nodes of the root to keep the path simple
+                let banned_nodes = self
+                    .ids()
+                    .map(|node| node != spur && root.contains(&node))
+                    .collect::<Vec<bool>>();
+                let Some(spur_path) = self.dijkstra(spur, to, &banned_nodes, &banned_edges) else {
+                    continue;
+                };
+                let Some(root_length) = self.path_length(root) else {
+                    continue;
+                };
+                let mut nodes = root.to_vec();
+                nodes.extend(spur_path.nodes.iter().skip(1));
+                let candidate = WeightedPath {
+                    length: root_length.saturating_add(spur_path.length),
+                    nodes,
+                };
+                if !candidates.contains(&candidate) && !found.contains(&candidate) {
+                    candidates.push(candidate);
+                }
+            }
+            let Some((best_idx, _)) = candidates
+                .iter()
+                .enumerate()
+                .min_by(|first, second| first.1.cmp(second.1))
+            else {
+                break;
+            };
+            found.push(candidates.swap_remove(best_idx));
+        }
+        found
+    }
+
+    /// Number of nodes
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    /// Finds the path with the largest total weight from `from` to `to`
+    ///
+    /// Returns `None` if there is no path.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error message if the graph contains a cycle or the length does not fit into a [`Weight`].
+    pub fn longest_path(&self, from: NodeId, to: NodeId) -> Result<Option<WeightedPath>, String> {
+        let order = self.topological_order().ok_or_else(|| {
+            format!(
+                "Cannot find the longest path in a graph with cycles! Nodes on cycles: {}",
+                self.describe_cycles()
+            )
+        })?;
+        let mut distances: Vec<Option<Weight>> = vec![None; self.len()];
+        let mut predecessors = vec![None; self.len()];
+        if let Some(start) = distances.get_mut(from) {
+            *start = Some(0);
+        }
+        for node in order {
+            let Some(distance) = distances.get(node).copied().flatten() else {
+                continue;
+            };
+            for (successor, weight) in self.edges(node) {
+                let new_distance = distance
+                    .checked_add(weight)
+                    .ok_or_else(|| "The length of the path does not fit into a u64!".to_owned())?;
+                if let (Some(best), Some(predecessor)) = (
+                    distances.get_mut(successor),
+                    predecessors.get_mut(successor),
+                ) && best.is_none_or(|known| new_distance > known)
+                {
+                    *best = Some(new_distance);
+                    *predecessor = Some(node);
+                }
+            }
+        }
+        Ok(distances
+            .get(to)
+            .copied()
+            .flatten()
+            .map(|length| WeightedPath {
+                length,
+                nodes: trace_back(&predecessors, to),
+            }))
+    }
+
+    /// Converts a list of nodes into a mask indexed by node ID
+    fn mark(&self, nodes: &[NodeId]) -> Vec<bool> {
+        let mut marked = vec![false; self.len()];
+        for &node in nodes {
+            if let Some(mark) = marked.get_mut(node) {
+                *mark = true;
+            }
+        }
+        marked
+    }
+
+    /// Name of a node
+    #[must_use]
+    pub fn name(&self, node: NodeId) -> Option<&str> {
+        self.names.get(node).map(String::as_str)
+    }
+
+    /// Creates an empty graph
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Successors of a node, empty if the node does not exist
+    #[must_use]
+    pub fn outgoing(&self, node: NodeId) -> &[NodeId] {
+        self.outgoing.get(node).map_or(&[], Vec::as_slice)
+    }
+
+    /// Sum of the weights along a sequence of nodes, or `None` if two consecutive nodes are not connected
+    #[must_use]
+    pub fn path_length(&self, nodes: &[NodeId]) -> Option<Weight> {
+        nodes
+            .windows(2)
+            .try_fold(0, |length: Weight, pair| match *pair {
+                [from, to] => length.checked_add(self.edge_weight(from, to)?),
+                _ => Some(length),
+            })
+    }
+
+    /// Lists all nodes in depth-first postorder, starting a new search at each unvisited node
+    fn postorder(&self) -> Vec<NodeId> {
+        let mut visited = vec![false; self.len()];
+        let mut order = Vec::with_capacity(self.len());
+        for root in self.ids() {
+            if let Some(seen) = visited.get_mut(root) {
+                if *seen {
+                    continue;
+                }
+                *seen = true;
+            }
+            // stack of the nodes being visited and the index of their next successor
+            let mut stack = vec![(root, 0_usize)];
+            while let Some(top) = stack.last_mut() {
+                let (node, next_idx) = *top;
+                let Some(&successor) = self.outgoing(node).get(next_idx) else {
+                    order.push(node);
+                    stack.pop();
+                    continue;
+                };
+                top.1 = next_idx.saturating_add(1);
+                if let Some(seen) = visited.get_mut(successor)
+                    && !*seen
+                {
+                    *seen = true;
+                    stack.push((successor, 0));
+                }
+            }
+        }
+        order
+    }
+
+    /// Marks all nodes that can be reached from `start`, indexed by node ID
+    #[must_use]
+    pub fn reachable_from(&self, start: NodeId) -> Vec<bool> {
+        self.mark(&self.bfs(start))
+    }
+
+    /// Marks all nodes from which `target` can be reached, indexed by node ID
+    #[must_use]
+    pub fn reaching(&self, target: NodeId) -> Vec<bool> {
+        self.mark(&self.traverse(target, Self::incoming, false))
+    }
+
+    /// Finds the path with the smallest total weight from `from` to `to` with Dijkstra's algorithm
+    ///
+    /// Returns `None` if there is no path.
+    #[must_use]
+    pub fn shortest_path(&self, from: NodeId, to: NodeId) -> Option<WeightedPath> {
+        self.dijkstra(from, to, &vec![false; self.len()], &BannedEdges::new())
+    }
+
+    /// Lazily enumerates the simple paths from `from` to `to` that visit all nodes in `via`
+    ///
+    /// The paths are found by depth-first search, which only keeps the current path in memory,
+    /// and never enters nodes from which `to` or a missing waypoint cannot be reached.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error message if there are too many waypoints.
+    pub fn simple_paths(
+        &self,
+        from: NodeId,
+        to: NodeId,
+        via: &[NodeId],
+    ) -> Result<SimplePaths<'_>, String> {
+        let all_waypoints = waypoint_masks(via)?.saturating_sub(1);
+        let can_reach_end = self.reaching(to);
+        let mut waypoints_ahead = vec![0_usize; self.len()];
+        for (idx, &waypoint) in via.iter().enumerate() {
+            for (ahead, reaches) in waypoints_ahead.iter_mut().zip(self.reaching(waypoint)) {
+                if reaches {
+                    *ahead |= 1_usize << idx;
+                }
+            }
+        }
+        let mut on_path = vec![false; self.len()];
+        let mut stack = Vec::new();
+        if let Some(start) = on_path.get_mut(from)
+            && can_reach_end.get(from) == Some(&true)
+        {
+            *start = true;
+            stack.push((from, 0, waypoint_bits(via, from)));
+        }
+        Ok(SimplePaths {
+            all_waypoints,
+            can_reach_end,
+            graph: self,
+            on_path,
+            stack,
+            to,
+            via: via.to_vec(),
+            waypoints_ahead,
+        })
+    }
+
+    /// Splits the nodes into strongly connected components, in which every node can reach
+    /// every other node
+    ///
+    /// The components are listed in topological order and their nodes are sorted by ID.
+    #[must_use]
+    pub fn strongly_connected_components(&self) -> Vec<Vec<NodeId>> {
+        // Kosaraju's algorithm: collect the components on the reversed edges
+        // in reverse postorder of a depth-first search on the forward edges
+        let mut assigned = vec![false; self.len()];
+        let mut components = Vec::new();
+        for root in self.postorder().into_iter().rev() {
+            let mut component = Vec::new();
+            let mut todo = vec![root];
+            while let Some(node) = todo.pop() {
+                let Some(done) = assigned.get_mut(node) else {
+                    continue;
+                };
+                if *done {
+                    continue;
+                }
+                *done = true;
+                component.push(node);
+                todo.extend(self.incoming(node));
+            }
+            if !component.is_empty() {
+                component.sort_unstable();
+                components.push(component);
+            }
+        }
+        components
+    }
+
+    /// Renders the graph in the Graphviz DOT format, styling each node with `style`
+    #[must_use]
+    pub fn to_dot<F: Fn(NodeId) -> NodeStyle>(&self, style: F) -> String {
+        let mut lines = vec!["digraph {".to_owned()];
+        for node in self.ids() {
+            let NodeStyle { highlight, note } = style(node);
+            let name = self.name(node).unwrap_or_default();
+            let text = note.map_or_else(|| name.to_owned(), |extra| format!("{name}\n{extra}"));
+            let label = text
+                .replace('\\', "\\\\")
+                .replace('"', "\\\"")
+                .replace('\n', "\\n");
+            let fill = highlight.map_or_else(String::new, |role| {
+                format!(", style=filled, fillcolor=\"{}\"", highlight_color(role))
+            });
+            lines.push(format!("    n{node} [label=\"{label}\"{fill}];"));
+        }
+        for node in self.ids() {
+            for (successor, weight) in self.edges(node) {
+                let label = if weight == 1 {
+                    String::new()
+                } else {
+                    format!(" [label=\"{weight}\"]")
+                };
+                lines.push(format!("    n{node} -> n{successor}{label};"));
+            }
+        }
+        lines.push("}\n".to_owned());
+        lines.join("\n")
+    }
+
+    /// Renders the graph as Mermaid flowchart, styling each node with `style`
+    #[must_use]
+    pub fn to_mermaid<F: Fn(NodeId) -> NodeStyle>(&self, style: F) -> String {
+        let mut lines = vec!["flowchart TD".to_owned()];
+        let mut classes = Vec::new();
+        for node in self.ids() {
+            let NodeStyle { highlight, note } = style(node);
+            let name = self.name(node).unwrap_or_default();
+            let text = note.map_or_else(|| name.to_owned(), |extra| format!("{name}<br>{extra}"));
+            let label = text.replace('"', "#quot;");
+            lines.push(format!("    n{node}[\"{label}\"]"));
+            if let Some(role) = highlight {
+                classes.push(format!("    class n{node} {}", highlight_class(role)));
+            }
+        }
+        for node in self.ids() {
+            for (successor, weight) in self.edges(node) {
+                let label = if weight == 1 {
+                    String::new()
+                } else {
+                    format!("|{weight}|")
+                };
+                lines.push(format!("    n{node} -->{label} n{successor}"));
+            }
+        }
+        for role in [Highlight::Start, Highlight::End, Highlight::Waypoint] {
+            let (class, color) = (highlight_class(role), highlight_color(role));
+            lines.push(format!("    classDef {class} fill:{color}"));
+        }
+        lines.extend(classes);
+        lines.push(String::new());
+        lines.join("\n")
+    }
+
+    /// Sorts the nodes so that every edge points from an earlier to a later node
+    ///
+    /// Returns `None` if the graph contains a cycle.
+    #[must_use]
+    pub fn topological_order(&self) -> Option<Vec<NodeId>> {
+        let mut in_degrees = self.incoming.iter().map(Vec::len).collect::<Vec<usize>>();
+        let mut ready = self
+            .ids()
+            .filter(|&node| in_degrees.get(node) == Some(&0))
+            .collect::<VecDeque<NodeId>>();
+        let mut order = Vec::with_capacity(self.len());
+        while let Some(node) = ready.pop_front() {
+            order.push(node);
+            for &successor in self.outgoing(node) {
+                let Some(in_degree) = in_degrees.get_mut(successor) else {
+                    continue;
+                };
+                *in_degree = in_degree.saturating_sub(1);
+                if *in_degree == 0 {
+                    ready.push_back(successor);
+                }
+            }
+        }
+        (order.len() == self.len()).then_some(order)
+    }
+
+    /// Lists all nodes reachable from `start` along the edges given by `neighbors`
+    ///
+    /// Visits the nodes depth first if `depth_first` is set and breadth first otherwise.
+    fn traverse(
+        &self,
+        start: NodeId,
+        neighbors: fn(&Self, NodeId) -> &[NodeId],
+        depth_first: bool,
+    ) -> Vec<NodeId> {
+        let mut visited = vec![false; self.len()];
+        let mut order = Vec::new();
+        let mut todo = VecDeque::from([start]);
+        while let Some(node) = if depth_first {
+            todo.pop_back()
+        } else {
+            todo.pop_front()
+        } {
+            let Some(seen) = visited.get_mut(node) else {
+                continue;
+            };
+            if *seen {
+                continue;
+            }
+            *seen = true;
+            order.push(node);
+            let successors = neighbors(self, node);
+            if depth_first {
+                // push in reverse so that the first successor is visited first
+                todo.extend(successors.iter().rev());
+            } else {
+                todo.extend(successors);
+            }
+        }
+        order
+    }
+
+    /// Counts the paths from `start` to every node by the subset of the waypoints in `via` that
+    /// they visit, including the waypoints at both ends
+    ///
+    /// `next` lists the neighbors in the direction of the paths, and `order` must list every
+    /// neighbor of a node after the node itself.
+    fn waypoint_counts(
+        &self,
+        start: NodeId,
+        via: &[NodeId],
+        order: &[NodeId],
+        next: fn(&Self, NodeId) -> &[NodeId],
+    ) -> Result<Vec<WaypointCounts>, String> {
+        let waypoint_bits = |node: NodeId| waypoint_bits(via, node);
+        let mut counts = vec![WaypointCounts::new(); self.len()];
+        add_paths(&mut counts, start, waypoint_bits(start), 1)?;
+        for &node in order {
+            // the counts of a node are final once it is reached in the order
+            let node_counts = counts.get_mut(node).map(mem::take).unwrap_or_default();
+            for (&mask, &count) in &node_counts {
+                for &neighbor in next(self, node) {
+                    add_paths(&mut counts, neighbor, mask | waypoint_bits(neighbor), count)?;
+                }
+            }
+            if let Some(slot) = counts.get_mut(node) {
+                *slot = node_counts;
+            }
+        }
+        Ok(counts)
+    }
+}
+
+impl Iterator for SimplePaths<'_> {
+    type Item = Vec<NodeId>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        while let Some(top) = self.stack.last_mut() {
+            let (node, next_idx, visited) = *top;
+            if node == self.to && next_idx != usize::MAX {
+                // paths may not pass through their end node
+                top.1 = usize::MAX;
+                if visited == self.all_waypoints {
+                    return Some(
+                        self.stack
+                            .iter()
+                            .map(|&(path_node, _, _)| path_node)
+                            .collect(),
+                    );
+                }
+            }
+            let Some(&successor) = self.graph.outgoing(node).get(top.1) else {
+                self.stack.pop();
+                if let Some(left) = self.on_path.get_mut(node) {
+                    *left = false;
+                }
+                continue;
+            };
+            top.1 = next_idx.saturating_add(1);
+            let successor_visited = visited | waypoint_bits(&self.via, successor);
+            let missing = self.all_waypoints & !successor_visited;
+            if self.can_reach_end.get(successor) != Some(&true)
+                || self
+                    .waypoints_ahead
+                    .get(successor)
+                    .is_none_or(|&ahead| missing & !ahead != 0)
+            {
+                continue;
+            }
+            if let Some(entered) = self.on_path.get_mut(successor)
+                && !*entered
+            {
+                *entered = true;
+                self.stack.push((successor, 0, successor_visited));
+            }
+        }
+        None
+    }
+}
+
+/// Adds `count` paths to the number of paths to `node` that visited the waypoints in `mask`
+fn add_paths(
+    counts: &mut [WaypointCounts],
+    node: NodeId,
+    mask: usize,
+    count: u128,
+) -> Result<(), String> {
+    let Some(node_counts) = counts.get_mut(node) else {
+        return Ok(());
+    };
+    let node_count = node_counts.entry(mask).or_insert(0);
+    *node_count = node_count
+        .checked_add(count)
+        .ok_or_else(|| "The number of paths does not fit into a u128!".to_owned())?;
+    Ok(())
+}
+
+/// Bit mask of the waypoints in `via` that `node` represents
+fn waypoint_bits(via: &[NodeId], node: NodeId) -> usize {
+    via.iter()
+        .enumerate()
+        .filter(|&(_, &waypoint)| waypoint == node)
+        .fold(0_usize, |bits, (idx, _)| bits | (1_usize << idx))
+}
+
+/// Number of subsets of the waypoints in `via`
+fn waypoint_masks(via: &[NodeId]) -> Result<usize, String> {
+    u32::try_from(via.len())
+        .ok()
+        .filter(|&len| len <= MAX_WAYPOINTS)
+        .and_then(|len| 1_usize.checked_shl(len))
+        .ok_or_else(|| format!("Cannot count paths via {} waypoints!", via.len()))
+}
+
+/// Name of the Mermaid class of highlighted nodes
+const fn highlight_class(role: Highlight) -> &'static str {
+    match role {
+        Highlight::End => "endNode",
+        Highlight::Start => "startNode",
+        Highlight::Waypoint => "waypoint",
+    }
+}
+
+/// Fill color of highlighted nodes
+const fn highlight_color(role: Highlight) -> &'static str {
+    match role {
+        Highlight::End => "#f08080",
+        Highlight::Start => "#90ee90",
+        Highlight::Waypoint => "#87cefa",
+    }
+}
+
+/// Follows the predecessors back from `end` and returns the nodes from the start to `end`
+fn trace_back(predecessors: &[Option<NodeId>], end: NodeId) -> Vec<NodeId> {
+    let mut nodes = vec![end];
+    let mut current = end;
+    while let Some(&Some(predecessor)) = predecessors.get(current) {
+        if nodes.len() > predecessors.len() {
+            // the predecessors form a cycle, which cannot happen for the searches in this module
+            break;
+        }
+        nodes.push(predecessor);
+        current = predecessor;
+    }
+    nodes.reverse();
+    nodes
+}
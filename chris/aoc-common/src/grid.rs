@@ -0,0 +1,300 @@
+//! Rectangular grids of cells, as used by many puzzles
+//!
+//! Positions are always given as `(row, column)` with `(0, 0)` in the top left corner.
+
+use crate::input::ParseError;
+
+/// Offsets of the orthogonal neighbors of a cell
+const ORTHOGONAL: [(isize, isize); 4] = [(-1, 0), (0, -1), (0, 1), (1, 0)];
+
+/// Offsets of the orthogonal and diagonal neighbors of a cell
+#[expect(
+    clippy::decimal_literal_representation,
+    reason = "The number of neighbors is more readable in decimal notation."
+)]
+const SURROUNDING: [(isize, isize); 8] = [
+    (-1, -1),
+    (-1, 0),
+    (-1, 1),
+    (0, -1),
+    (0, 1),
+    (1, -1),
+    (1, 0),
+    (1, 1),
+];
+
+/// Position of a cell as `(row, column)`
+pub type Position = (usize, usize);
+
+/// A rectangular grid of cells of type `T`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    /// The cells in order from left to right and top to bottom
+    cells: Vec<T>,
+    /// Number of columns
+    columns: usize,
+    /// Number of rows
+    rows: usize,
+}
+
+impl<T> Grid<T> {
+    /// Iterates over the cells of a column from top to bottom
+    ///
+    /// The iterator is empty if the column does not exist.
+    pub fn column(&self, col: usize) -> impl Iterator<Item = &T> {
+        let rows = if col < self.columns { self.rows } else { 0 };
+        self.cells
+            .iter()
+            .skip(col)
+            .step_by(self.columns.max(1))
+            .take(rows)
+    }
+
+    /// Number of columns
+    #[must_use]
+    pub const fn columns(&self) -> usize {
+        self.columns
+    }
+
+    /// Creates a grid from its rows, returns `None` if the rows differ in length
+    #[must_use]
+    pub fn from_rows(rows: Vec<Vec<T>>) -> Option<Self> {
+        let num_rows = rows.len();
+        let columns = rows.first().map_or(0, Vec::len);
+        if rows.iter().any(|row| row.len() != columns) {
+            return None;
+        }
+        Some(Self {
+            cells: rows.into_iter().flatten().collect(),
+            columns,
+            rows: num_rows,
+        })
+    }
+
+    /// Returns the cell at the given position or `None` if it is outside of the grid
+    #[must_use]
+    pub fn get(&self, row: usize, col: usize) -> Option<&T> {
+        self.cells.get(self.index(row, col)?)
+    }
+
+    /// Returns the cell at the given position mutably or `None` if it is outside of the grid
+    #[must_use]
+    pub fn get_mut(&mut self, row: usize, col: usize) -> Option<&mut T> {
+        let idx = self.index(row, col)?;
+        self.cells.get_mut(idx)
+    }
+
+    /// Returns the cell at the given position or `None` if it is outside of the grid,
+    /// allowing negative coordinates
+    #[must_use]
+    pub fn get_signed(&self, row: isize, col: isize) -> Option<&T> {
+        self.get(usize::try_from(row).ok()?, usize::try_from(col).ok()?)
+    }
+
+    /// Converts a position into an index of `cells` if it is inside of the grid
+    fn index(&self, row: usize, col: usize) -> Option<usize> {
+        if row >= self.rows || col >= self.columns {
+            return None;
+        }
+        row.checked_mul(self.columns)?.checked_add(col)
+    }
+
+    /// Iterates over all positions and their cells, row by row
+    pub fn iter(&self) -> impl Iterator<Item = (Position, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    /// Iterates over the rows from top to bottom
+    #[must_use]
+    pub fn iter_rows(&self) -> impl DoubleEndedIterator<Item = &[T]> {
+        self.cells.chunks(self.columns.max(1))
+    }
+
+    /// Creates a grid of the same size by converting each cell
+    #[must_use]
+    pub fn map<U, F: FnMut(&T) -> U>(&self, convert: F) -> Grid<U> {
+        Grid {
+            cells: self.cells.iter().map(convert).collect(),
+            columns: self.columns,
+            rows: self.rows,
+        }
+    }
+
+    /// Creates a grid of the same size by converting each cell based on its position and value
+    #[must_use]
+    pub fn map_indexed<U, F: FnMut(Position, &T) -> U>(&self, mut convert: F) -> Grid<U> {
+        Grid {
+            cells: self.iter().map(|(pos, cell)| convert(pos, cell)).collect(),
+            columns: self.columns,
+            rows: self.rows,
+        }
+    }
+
+    /// Iterates over the existing neighbors of a cell with the given offsets
+    fn neighbors(
+        &self,
+        row: usize,
+        col: usize,
+        offsets: &'static [(isize, isize)],
+    ) -> impl Iterator<Item = (Position, &T)> {
+        offsets.iter().filter_map(move |&(d_row, d_col)| {
+            let pos = (
+                row.checked_add_signed(d_row)?,
+                col.checked_add_signed(d_col)?,
+            );
+            Some((pos, self.get(pos.0, pos.1)?))
+        })
+    }
+
+    /// Iterates over the up to four orthogonal neighbors of a cell
+    pub fn neighbors4(&self, row: usize, col: usize) -> impl Iterator<Item = (Position, &T)> {
+        self.neighbors(row, col, &ORTHOGONAL)
+    }
+
+    /// Iterates over the up to eight orthogonal and diagonal neighbors of a cell
+    pub fn neighbors8(&self, row: usize, col: usize) -> impl Iterator<Item = (Position, &T)> {
+        self.neighbors(row, col, &SURROUNDING)
+    }
+
+    /// Parses a grid with one row per line and one cell per character
+    ///
+    /// `parse_cell` converts a character into a cell or returns `None` if the character is not
+    /// allowed, in which case the error states that `expected` was expected instead.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the input is empty, the lines differ in length or
+    /// a character cannot be converted into a cell.
+    pub fn parse<F: FnMut(char) -> Option<T>>(
+        content: &str,
+        expected: &str,
+        mut parse_cell: F,
+    ) -> Result<Self, ParseError> {
+        let mut rows = Vec::new();
+        let mut columns = None;
+        for (idx, line) in content.lines().enumerate() {
+            let text = line.trim_end();
+            let row = text
+                .char_indices()
+                .map(|(col, chr)| {
+                    parse_cell(chr).ok_or_else(|| {
+                        ParseError::at(idx, line, col..col.saturating_add(chr.len_utf8()), expected)
+                    })
+                })
+                .collect::<Result<Vec<T>, ParseError>>()?;
+            let expected_columns = *columns.get_or_insert(row.len());
+            if row.len() != expected_columns {
+                return Err(ParseError::at_token(
+                    idx,
+                    line,
+                    text,
+                    &format!("a row with {expected_columns} cells like the first one"),
+                ));
+            }
+            rows.push(row);
+        }
+        if rows.is_empty() {
+            return Err(ParseError::in_input("at least one row of the grid"));
+        }
+        Self::from_rows(rows).ok_or_else(|| ParseError::in_input("rows of equal length"))
+    }
+
+    /// Iterates over all positions of the grid, row by row
+    pub fn positions(&self) -> impl Iterator<Item = Position> + use<T> {
+        let columns = self.columns;
+        (0..self.rows).flat_map(move |row| (0..columns).map(move |col| (row, col)))
+    }
+
+    /// Returns a row or `None` if it does not exist
+    #[must_use]
+    pub fn row(&self, row: usize) -> Option<&[T]> {
+        let start = self.index(row, 0)?;
+        self.cells.get(start..start.checked_add(self.columns)?)
+    }
+
+    /// Number of rows
+    #[must_use]
+    pub const fn rows(&self) -> usize {
+        self.rows
+    }
+
+    /// Iterates over all cells, row by row
+    pub fn values(&self) -> impl Iterator<Item = &T> {
+        self.cells.iter()
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    /// Creates a grid with `rows` rows and `columns` columns where each cell is `value`
+    #[must_use]
+    pub fn filled(rows: usize, columns: usize, value: T) -> Self {
+        Self {
+            cells: vec![value; rows.saturating_mul(columns)],
+            columns,
+            rows,
+        }
+    }
+
+    /// Returns a copy with the order of the cells in each row reversed (mirrored left to right)
+    #[must_use]
+    pub fn flipped_horizontally(&self) -> Self {
+        Self {
+            cells: self
+                .iter_rows()
+                .flat_map(|row| row.iter().rev().cloned())
+                .collect(),
+            columns: self.columns,
+            rows: self.rows,
+        }
+    }
+
+    /// Returns a copy with the order of the rows reversed (mirrored top to bottom)
+    #[must_use]
+    pub fn flipped_vertically(&self) -> Self {
+        Self {
+            cells: self
+                .iter_rows()
+                .rev()
+                .flat_map(|row| row.iter().cloned())
+                .collect(),
+            columns: self.columns,
+            rows: self.rows,
+        }
+    }
+
+    /// Creates a grid from rows of different length by filling up short rows with `fill`
+    #[must_use]
+    pub fn from_rows_padded(rows: Vec<Vec<T>>, fill: &T) -> Self {
+        let columns = rows.iter().map(Vec::len).max().unwrap_or(0);
+        let padded = rows
+            .into_iter()
+            .map(|mut row| {
+                row.resize(columns, fill.clone());
+                row
+            })
+            .collect::<Vec<Vec<T>>>();
+        Self {
+            rows: padded.len(),
+            cells: padded.into_iter().flatten().collect(),
+            columns,
+        }
+    }
+
+    /// Returns a copy that is rotated clockwise by 90 degrees
+    #[must_use]
+    pub fn rotated_clockwise(&self) -> Self {
+        self.flipped_vertically().transposed()
+    }
+
+    /// Returns a copy where rows and columns are swapped
+    #[must_use]
+    pub fn transposed(&self) -> Self {
+        Self {
+            cells: (0..self.columns)
+                .flat_map(|col| self.column(col).cloned())
+                .collect(),
+            columns: self.rows,
+            rows: self.columns,
+        }
+    }
+}
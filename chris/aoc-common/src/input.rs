@@ -0,0 +1,141 @@
+//! Diagnostics for malformed puzzle inputs
+//!
+//! All parsers report problems with a [`ParseError`], which points to the offending part of the
+//! input and renders it like a compiler error:
+//!
+//! ```text
+//! line 3, column 2: expected a number
+//!   |
+//! 3 | L6x8
+//!   |  ^^^
+//! ```
+
+use core::error::Error;
+use core::fmt;
+use core::ops::Range;
+use core::str::FromStr;
+
+/// Location of the offending text in the puzzle input
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Span {
+    /// Byte range of the offending text within the line
+    columns: Range<usize>,
+    /// Number of the line (1-based)
+    line: usize,
+    /// Content of the offending line
+    text: String,
+}
+
+/// Error for malformed puzzle inputs
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// Description of what was expected instead of the offending text
+    expected: String,
+    /// Location of the offending text, if the error can be attributed to a single line
+    span: Option<Span>,
+}
+
+impl ParseError {
+    /// Creates an error for the byte range `columns` of the line `text` with the 0-based index `line_idx`
+    #[must_use]
+    pub fn at(line_idx: usize, text: &str, columns: Range<usize>, expected: &str) -> Self {
+        Self {
+            expected: expected.to_owned(),
+            span: Some(Span {
+                columns,
+                line: line_idx.saturating_add(1),
+                text: text.to_owned(),
+            }),
+        }
+    }
+
+    /// Creates an error for the whole line `text` with the 0-based index `line_idx`
+    #[must_use]
+    pub fn at_line(line_idx: usize, text: &str, expected: &str) -> Self {
+        Self::at(line_idx, text, 0..text.len(), expected)
+    }
+
+    /// Creates an error for `token`, which must be a slice of the line `text`
+    ///
+    /// If `token` is not part of `text`, the error points to the whole line instead.
+    #[must_use]
+    pub fn at_token(line_idx: usize, text: &str, token: &str, expected: &str) -> Self {
+        let columns = token
+            .as_ptr()
+            .addr()
+            .checked_sub(text.as_ptr().addr())
+            .map(|start| start..start.saturating_add(token.len()))
+            .filter(|columns| columns.end <= text.len());
+        columns.map_or_else(
+            || Self::at_line(line_idx, text, expected),
+            |cols| Self::at(line_idx, text, cols, expected),
+        )
+    }
+
+    /// Byte range of the offending text within its line
+    #[must_use]
+    pub fn columns(&self) -> Option<Range<usize>> {
+        self.span.as_ref().map(|span| span.columns.clone())
+    }
+
+    /// Description of what was expected instead of the offending text
+    #[must_use]
+    pub fn expected(&self) -> &str {
+        &self.expected
+    }
+
+    /// Creates an error that concerns the input as a whole instead of a single line
+    #[must_use]
+    pub fn in_input(expected: &str) -> Self {
+        Self {
+            expected: expected.to_owned(),
+            span: None,
+        }
+    }
+
+    /// Number of the offending line (1-based)
+    #[must_use]
+    pub fn line(&self) -> Option<usize> {
+        self.span.as_ref().map(|span| span.line)
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let expected = &self.expected;
+        let Some(span) = self.span.as_ref() else {
+            return write!(f, "expected {expected}");
+        };
+        let line = span.line;
+        let text = &span.text;
+        // count characters instead of bytes so that the carets line up with the text
+        let chars_before = |idx: usize| text.get(..idx).map_or(0, |prefix| prefix.chars().count());
+        let indent = chars_before(span.columns.start);
+        let width = chars_before(span.columns.end).saturating_sub(indent).max(1);
+        let column = indent.saturating_add(1);
+        let gutter = " ".repeat(line.to_string().len());
+        let carets = "^".repeat(width);
+        writeln!(f, "line {line}, column {column}: expected {expected}")?;
+        writeln!(f, "{gutter} |")?;
+        writeln!(f, "{line} | {text}")?;
+        write!(f, "{gutter} | {:indent$}{carets}", "")
+    }
+}
+
+impl Error for ParseError {}
+
+/// Parses `token`, which must be a slice of the line `text`, into a value of type `T`
+///
+/// # Errors
+///
+/// Returns a [`ParseError`] pointing to `token` if it cannot be parsed.
+pub fn parse_token<T: FromStr>(
+    line_idx: usize,
+    text: &str,
+    token: &str,
+    expected: &str,
+) -> Result<T, ParseError> {
+    token
+        .parse::<T>()
+        .map_err(|_err| ParseError::at_token(line_idx, text, token, expected))
+}
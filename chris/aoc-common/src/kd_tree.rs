@@ -0,0 +1,272 @@
+//! Spatial index over points in space for nearest-neighbor and radius queries
+//!
+//! The tree is stored implicitly: the indices of the points are arranged so that the median of
+//! every range splits it along the axis of its depth, cycling through x, y and z.
+
+extern crate alloc;
+use alloc::collections::BinaryHeap;
+use core::cmp::Reverse;
+use core::ops::Range;
+
+use crate::geometry::Point3;
+use crate::metric::Metric;
+
+/// Number of neighbors that are fetched at once when listing pairs
+const NEIGHBOR_BATCH: usize = 4;
+
+/// Point found by a query with its distance to the target, ordered by distance first
+pub type Neighbor = (u64, usize);
+
+/// Pair of points with the given indices, preceded by their distance
+pub type Pair = (u64, usize, usize);
+
+/// k-d tree over a slice of points, which are referenced by their index in the slice
+///
+/// Distances are measured with the metric `M`.
+#[derive(Debug, Clone)]
+pub struct KdTree<'points, M: Metric> {
+    /// Metric for all distances
+    metric: M,
+    /// Indices of the points, arranged so that the median of each range splits it
+    order: Vec<usize>,
+    /// Points in the order of the input
+    points: &'points [Point3<i64>],
+}
+
+/// Lazy iterator over all pairs of points ordered by their distance, created by [`KdTree::pairs`]
+#[derive(Debug, Clone)]
+pub struct Pairs<'tree, 'points, M: Metric> {
+    /// Number of neighbors that have been fetched for each point
+    fetched: Vec<usize>,
+    /// Fetched partners of each point that have not been queued yet, the closest one last
+    pending: Vec<Vec<Neighbor>>,
+    /// Closest pair for each point that has not been yielded yet
+    queue: BinaryHeap<Reverse<Pair>>,
+    /// Tree used to find the neighbors
+    tree: &'tree KdTree<'points, M>,
+}
+
+impl<'points, M: Metric> KdTree<'points, M> {
+    /// Finds the `count` points closest to `target`, including a point at the target itself
+    ///
+    /// The points are ordered by their distance and points at the same distance by their index.
+    #[must_use]
+    pub fn nearest(&self, target: &Point3<i64>, count: usize) -> Vec<Neighbor> {
+        let mut best = BinaryHeap::new();
+        if count > 0 {
+            self.search_nearest(*target, count, 0..self.order.len(), 0, &mut best);
+        }
+        best.into_sorted_vec()
+    }
+
+    /// Builds the tree in O(n log n) time
+    #[must_use]
+    pub fn new(points: &'points [Point3<i64>], metric: M) -> Self {
+        let mut order = (0..points.len()).collect::<Vec<usize>>();
+        build(points, &mut order, 0);
+        Self {
+            metric,
+            order,
+            points,
+        }
+    }
+
+    /// Lazily lists all pairs of different points, shortest first
+    ///
+    /// Pairs of equal length are ordered by the indices of their points, so the order is the
+    /// same as sorting all pairs. Each point only fetches a few neighbors at a time, so taking
+    /// the first pairs is fast even for many points.
+    #[must_use]
+    pub fn pairs(&self) -> Pairs<'_, 'points, M> {
+        let mut pairs = Pairs {
+            fetched: vec![0; self.points.len()],
+            pending: vec![Vec::new(); self.points.len()],
+            queue: BinaryHeap::new(),
+            tree: self,
+        };
+        for point in 0..self.points.len() {
+            pairs.queue_next(point);
+        }
+        pairs
+    }
+
+    /// Adds the points of `range` that are closer than the current `best` ones to it
+    fn search_nearest(
+        &self,
+        target: Point3<i64>,
+        count: usize,
+        range: Range<usize>,
+        axis: usize,
+        best: &mut BinaryHeap<Neighbor>,
+    ) {
+        let Some((point, split)) = self.split(&range) else {
+            return;
+        };
+        let candidate = (self.metric.distance(&target, point), split);
+        best.push(candidate);
+        if best.len() > count {
+            best.pop();
+        }
+        let (near, far, plane) = self.sides(target, range, axis);
+        self.search_nearest(target, count, near, next_axis(axis), best);
+        // points at the same distance may still win by their index
+        if best.len() < count || best.peek().is_some_and(|&(worst, _)| plane <= worst) {
+            self.search_nearest(target, count, far, next_axis(axis), best);
+        }
+    }
+
+    /// Adds the points of `range` within the radius around `target` to `found`
+    fn search_radius(
+        &self,
+        target: Point3<i64>,
+        radius: u64,
+        range: Range<usize>,
+        axis: usize,
+        found: &mut Vec<Neighbor>,
+    ) {
+        let Some((point, split)) = self.split(&range) else {
+            return;
+        };
+        let distance = self.metric.distance(&target, point);
+        if distance <= radius {
+            found.push((distance, split));
+        }
+        let (near, far, plane) = self.sides(target, range, axis);
+        self.search_radius(target, radius, near, next_axis(axis), found);
+        if plane <= radius {
+            self.search_radius(target, radius, far, next_axis(axis), found);
+        }
+    }
+
+    /// Splits a range into the side of `target`, the other side and the smallest distance of
+    /// `target` to a point behind the splitting plane
+    fn sides(
+        &self,
+        target: Point3<i64>,
+        range: Range<usize>,
+        axis: usize,
+    ) -> (Range<usize>, Range<usize>, u64) {
+        let middle = midpoint(&range);
+        let split = self
+            .order
+            .get(middle)
+            .and_then(|&idx| self.points.get(idx))
+            .map_or(0, |point| point.coordinate(axis));
+        let target_coord = target.coordinate(axis);
+        let left = range.start..middle;
+        let right = middle.saturating_add(1)..range.end;
+        let offset = target_coord.abs_diff(split);
+        let plane = self.metric.plane_distance(offset);
+        if target_coord < split {
+            (left, right, plane)
+        } else {
+            (right, left, plane)
+        }
+    }
+
+    /// Returns the point that splits a range and its index, or `None` if the range is empty
+    fn split(&self, range: &Range<usize>) -> Option<(&'points Point3<i64>, usize)> {
+        if range.is_empty() {
+            return None;
+        }
+        let idx = *self.order.get(midpoint(range))?;
+        Some((self.points.get(idx)?, idx))
+    }
+
+    /// Finds all points whose distance to `target` is at most `radius`
+    ///
+    /// The radius is measured by the metric, so it is squared for the Euclidean metric.
+    ///
+    /// The points are ordered by their distance and points at the same distance by their index.
+    #[must_use]
+    pub fn within(&self, target: &Point3<i64>, radius: u64) -> Vec<Neighbor> {
+        let mut found = Vec::new();
+        self.search_radius(*target, radius, 0..self.order.len(), 0, &mut found);
+        found.sort_unstable();
+        found
+    }
+}
+
+impl<M: Metric> Pairs<'_, '_, M> {
+    /// Queues the next closest partner of `point`, fetching more neighbors from the tree
+    /// if necessary
+    fn queue_next(&mut self, point: usize) {
+        let total = self.tree.points.len();
+        loop {
+            let Some(pending) = self.pending.get_mut(point) else {
+                return;
+            };
+            if let Some((distance, partner)) = pending.pop() {
+                self.queue.push(Reverse((distance, point, partner)));
+                return;
+            }
+            let Some(fetched) = self.fetched.get_mut(point) else {
+                return;
+            };
+            if *fetched >= total {
+                return;
+            }
+            let already = *fetched;
+            *fetched = already.saturating_mul(2).max(NEIGHBOR_BATCH).min(total);
+            let count = *fetched;
+            let Some(target) = self.tree.points.get(point) else {
+                return;
+            };
+            // only keep the partners that were not fetched before
+            let mut partners = self
+                .tree
+                .nearest(target, count)
+                .into_iter()
+                .skip(already)
+                .filter(|&(_, partner)| partner != point)
+                .collect::<Vec<Neighbor>>();
+            partners.reverse();
+            *pending = partners;
+        }
+    }
+}
+
+impl<M: Metric> Iterator for Pairs<'_, '_, M> {
+    type Item = Pair;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        // every pair is queued by both of its points, but only yielded by the one with the
+        // smaller index
+        loop {
+            let Reverse(pair) = self.queue.pop()?;
+            self.queue_next(pair.1);
+            if pair.1 < pair.2 {
+                return Some(pair);
+            }
+        }
+    }
+}
+
+/// Arranges `order` so that the median along `axis` splits it, then does the same for both halves
+fn build(points: &[Point3<i64>], order: &mut [usize], axis: usize) {
+    if order.len() <= 1 {
+        return;
+    }
+    let middle = midpoint(&(0..order.len()));
+    order.select_nth_unstable_by_key(middle, |&idx| {
+        (
+            points.get(idx).map_or(0, |point| point.coordinate(axis)),
+            idx,
+        )
+    });
+    let (left, rest) = order.split_at_mut(middle);
+    build(points, left, next_axis(axis));
+    if let Some(right) = rest.get_mut(1..) {
+        build(points, right, next_axis(axis));
+    }
+}
+
+/// Index in the middle of a range, which holds the splitting point
+const fn midpoint(range: &Range<usize>) -> usize {
+    range.start.midpoint(range.end)
+}
+
+/// Axis that splits the next level of the tree
+const fn next_axis(axis: usize) -> usize {
+    if axis >= 2 { 0 } else { axis.saturating_add(1) }
+}
@@ -0,0 +1,57 @@
+//! Shared utilities for the Advent of Code 2025 solutions
+//!
+//! Every day needs to find its input file, read it, report errors and set up logging.
+//! This crate bundles these steps so that each day only has to implement the
+//! [`Solution`](solution::Solution) trait with the parser and the solver functions.
+//! Parsers report malformed inputs with a [`ParseError`](input::ParseError) that points to the offending line.
+//! Puzzles on a map of characters can use the [`Grid`](grid::Grid) type and puzzles on networks the [`Graph`](graph::Graph) type.
+//! Points in the plane or in space with signed or floating-point coordinates are in the [`geometry`] module.
+//! The [`metric`] module measures distances between points in space in different ways, and a
+//! [`KdTree`](kd_tree::KdTree) finds the points closest to a target or to each other.
+//! Clustering puzzles can merge groups with [`UnionFind`](union_find::UnionFind).
+//! The [`regression`] module checks these solutions against known answers in the tests of each day
+//! and the [`bench`](mod@bench) module measures how fast they are.
+//! With the `test-util` feature, the `test_util` module generates reproducible test inputs.
+
+pub mod bench;
+pub mod geometry;
+pub mod graph;
+pub mod grid;
+pub mod input;
+pub mod kd_tree;
+pub mod metric;
+pub mod regression;
+pub mod solution;
+#[cfg(feature = "test-util")]
+pub mod test_util;
+pub mod union_find;
+
+use std::{fs, io, path::Path, process::exit};
+
+/// Initializes the logger, which can be configured with the `RUST_LOG` environment variable
+pub fn init_logger() {
+    env_logger::init();
+}
+
+/// Reads the puzzle input from `path`
+///
+/// # Errors
+///
+/// Returns an error message containing the path if the file cannot be read.
+pub fn read_input(path: &Path) -> Result<String, String> {
+    fs::read_to_string(path).map_err(|err: io::Error| {
+        let path_disp = path.display();
+        format!("Could not read {path_disp}!\nReason: Err({err})")
+    })
+}
+
+/// Reports an error on stderr and exits the program with status code 1
+#[expect(
+    clippy::print_stderr,
+    clippy::exit,
+    reason = "This is the central error reporting for our CLI functions."
+)]
+pub fn exit_with_error(msg: &str) -> ! {
+    eprintln!("{msg}");
+    exit(1);
+}
@@ -0,0 +1,138 @@
+//! Ways to measure the distance between points in space
+//!
+//! All distances are exact integers. The Euclidean metric uses squared distances, which are
+//! ordered like the actual distances, and [`Metric::length`] converts them back for display.
+
+use clap::ValueEnum;
+
+use crate::geometry::Point3;
+
+/// Measures distances between points with integer coordinates
+pub trait Metric: Copy {
+    /// Distance between two points
+    fn distance(&self, first: &Point3<i64>, second: &Point3<i64>) -> u64;
+
+    /// Distance that corresponds to the given length, e.g. for a search radius
+    fn distance_for_length(&self, length: u64) -> u64 {
+        length
+    }
+
+    /// Length that corresponds to the given distance, e.g. for display
+    #[expect(
+        clippy::cast_precision_loss,
+        clippy::as_conversions,
+        reason = "Lengths are only displayed."
+    )]
+    fn length(&self, distance: u64) -> f64 {
+        distance as f64
+    }
+
+    /// Smallest possible distance to a point whose coordinate on one axis differs by `offset`
+    ///
+    /// This allows a spatial index to skip everything behind a splitting plane.
+    fn plane_distance(&self, offset: u64) -> u64 {
+        offset
+    }
+}
+
+/// Largest difference on any axis, like a crane that moves on all axes at the same time
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Chebyshev;
+
+/// Straight-line distance, measured as its exact square
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Euclidean;
+
+/// Sum of the differences on all axes, like a cable that only runs parallel to the axes
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Manhattan;
+
+/// Metric that is chosen at runtime, e.g. on the command line
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, ValueEnum)]
+pub enum Routing {
+    /// Largest difference on any axis
+    Chebyshev,
+    /// Straight-line distance
+    #[default]
+    Euclidean,
+    /// Sum of the differences on all axes
+    Manhattan,
+}
+
+impl Metric for Chebyshev {
+    fn distance(&self, first: &Point3<i64>, second: &Point3<i64>) -> u64 {
+        differences(*first, *second).into_iter().max().unwrap_or(0)
+    }
+}
+
+impl Metric for Euclidean {
+    fn distance(&self, first: &Point3<i64>, second: &Point3<i64>) -> u64 {
+        differences(*first, *second)
+            .into_iter()
+            .fold(0, |sum, diff| sum.saturating_add(diff.saturating_mul(diff)))
+    }
+
+    fn distance_for_length(&self, length: u64) -> u64 {
+        length.saturating_mul(length)
+    }
+
+    #[expect(
+        clippy::cast_precision_loss,
+        clippy::as_conversions,
+        reason = "We have to convert to float to take the square root."
+    )]
+    fn length(&self, distance: u64) -> f64 {
+        (distance as f64).sqrt()
+    }
+
+    fn plane_distance(&self, offset: u64) -> u64 {
+        offset.saturating_mul(offset)
+    }
+}
+
+impl Metric for Manhattan {
+    fn distance(&self, first: &Point3<i64>, second: &Point3<i64>) -> u64 {
+        differences(*first, *second)
+            .into_iter()
+            .fold(0, u64::saturating_add)
+    }
+}
+
+impl Metric for Routing {
+    fn distance(&self, first: &Point3<i64>, second: &Point3<i64>) -> u64 {
+        match *self {
+            Self::Chebyshev => Chebyshev.distance(first, second),
+            Self::Euclidean => Euclidean.distance(first, second),
+            Self::Manhattan => Manhattan.distance(first, second),
+        }
+    }
+
+    fn distance_for_length(&self, length: u64) -> u64 {
+        match *self {
+            Self::Chebyshev => Chebyshev.distance_for_length(length),
+            Self::Euclidean => Euclidean.distance_for_length(length),
+            Self::Manhattan => Manhattan.distance_for_length(length),
+        }
+    }
+
+    fn length(&self, distance: u64) -> f64 {
+        match *self {
+            Self::Chebyshev => Chebyshev.length(distance),
+            Self::Euclidean => Euclidean.length(distance),
+            Self::Manhattan => Manhattan.length(distance),
+        }
+    }
+
+    fn plane_distance(&self, offset: u64) -> u64 {
+        match *self {
+            Self::Chebyshev => Chebyshev.plane_distance(offset),
+            Self::Euclidean => Euclidean.plane_distance(offset),
+            Self::Manhattan => Manhattan.plane_distance(offset),
+        }
+    }
+}
+
+/// Absolute differences of two points on each axis
+fn differences(first: Point3<i64>, second: Point3<i64>) -> [u64; 3] {
+    [0, 1, 2].map(|axis| first.coordinate(axis).abs_diff(second.coordinate(axis)))
+}
@@ -0,0 +1,95 @@
+//! Regression checks against the known answers of a day
+//!
+//! Each day stores the answers that are known to be correct in `expected_answers.txt`.
+//! Every non-empty line that does not start with `#` has the form `<input file> <part> <answer>`,
+//! e.g. `sample_input.txt 1 42`.
+//! The input file is relative to the folder of the day.
+
+use std::path::{Path, PathBuf};
+
+use clap::ValueEnum as _;
+
+use crate::read_input;
+use crate::solution::{InputKind, Part, Solution};
+
+/// Name of the file that contains the expected answers of a day
+pub const EXPECTED_ANSWERS_FILE: &str = "expected_answers.txt";
+
+/// A known answer for a part of the puzzle on a specific input file
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ExpectedAnswer {
+    /// The expected answer
+    pub answer: String,
+    /// Input file, relative to the folder of the day
+    pub input: PathBuf,
+    /// Part of the puzzle
+    pub part: Part,
+}
+
+/// Parses the contents of an expected answers file
+///
+/// # Errors
+///
+/// Returns an error message if a line does not have the form `<input file> <part> <answer>`.
+pub fn parse_expected_answers(content: &str) -> Result<Vec<ExpectedAnswer>, String> {
+    content
+        .lines()
+        .enumerate()
+        .filter(|&(_, line)| !line.trim().is_empty() && !line.trim_start().starts_with('#'))
+        .map(|(idx, line)| {
+            let line_nr = idx.saturating_add(1);
+            let fields = line.split_whitespace().collect::<Vec<&str>>();
+            let &[input, part_str, answer] = fields.as_slice() else {
+                return Err(format!(
+                    "Line {line_nr} should have the form `<input file> <part> <answer>`: {line}"
+                ));
+            };
+            let part = Part::from_str(part_str, false)
+                .map_err(|err| format!("Invalid part in line {line_nr}: {err}"))?;
+            Ok(ExpectedAnswer {
+                answer: answer.to_owned(),
+                input: PathBuf::from(input),
+                part,
+            })
+        })
+        .collect()
+}
+
+/// Checks the solution `S` against the expected answers stored in `folder`
+///
+/// Entries for input files that do not exist are skipped, because the personal puzzle inputs
+/// are not checked in.
+///
+/// # Errors
+///
+/// Returns an error message listing all answers that differ from the expected ones
+/// or could not be computed at all.
+pub fn check_expected_answers<S: Solution>(folder: &Path) -> Result<(), String> {
+    let expected = parse_expected_answers(&read_input(&folder.join(EXPECTED_ANSWERS_FILE))?)?;
+    let mut failures = Vec::new();
+    for entry in expected {
+        let path = folder.join(&entry.input);
+        if !path.exists() {
+            continue;
+        }
+        let part = entry.part;
+        let input = entry.input.display();
+        match S::solve(part, &read_input(&path)?, InputKind::of(&path)) {
+            Ok(answer) if answer == entry.answer => {}
+            Ok(answer) => failures.push(format!(
+                "Day {}, part {part} on {input}: expected {}, got {answer}",
+                S::DAY,
+                entry.answer
+            )),
+            Err(err) => failures.push(format!(
+                "Day {}, part {part} on {input} failed: {err}",
+                S::DAY
+            )),
+        }
+    }
+    if failures.is_empty() {
+        Ok(())
+    } else {
+        Err(failures.join("\n"))
+    }
+}
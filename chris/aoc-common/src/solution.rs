@@ -0,0 +1,203 @@
+//! Common interface for the solutions of all days
+
+use core::fmt::{self, Debug, Display};
+use std::ffi::OsStr;
+use std::path::{Path, PathBuf};
+
+use clap::{Args, Parser, ValueEnum};
+use log::info;
+
+use crate::input::ParseError;
+use crate::{exit_with_error, init_logger, read_input};
+
+/// Handler for the additional command line arguments `E` of a day with the parsed input `I`
+///
+/// Returns the output to print instead of the answers, if the arguments ask for it.
+pub type ExtraHandler<E, I> = fn(&E, &I) -> Result<Option<String>, String>;
+
+/// Kind of a puzzle input, because some puzzles use other numbers for the sample
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub enum InputKind {
+    /// Personal puzzle input
+    #[default]
+    Puzzle,
+    /// Sample input from the exercise description
+    Sample,
+}
+
+impl InputKind {
+    /// Kind of the input file at `path`, which is a sample if its name starts with `sample`
+    #[must_use]
+    pub fn of(path: &Path) -> Self {
+        let is_sample = path
+            .file_name()
+            .and_then(OsStr::to_str)
+            .is_some_and(|name| name.starts_with("sample"));
+        if is_sample {
+            Self::Sample
+        } else {
+            Self::Puzzle
+        }
+    }
+}
+
+/// Part of a puzzle, each day consists of two parts
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, ValueEnum)]
+pub enum Part {
+    /// First part of the puzzle
+    #[value(name = "1")]
+    One,
+    /// Second part of the puzzle
+    #[value(name = "2")]
+    Two,
+}
+
+impl Part {
+    /// Both parts in order
+    pub const ALL: [Self; 2] = [Self::One, Self::Two];
+}
+
+impl Display for Part {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            Self::One => write!(f, "1"),
+            Self::Two => write!(f, "2"),
+        }
+    }
+}
+
+/// Command line arguments for running the solution of a single day
+#[derive(Debug, Parser)]
+#[command(about = "Solves a puzzle of Advent of Code 2025")]
+struct DayArgs<E: Args> {
+    /// Additional arguments of the day
+    #[command(flatten)]
+    extra: E,
+    /// Input file to use instead of the default input of the day
+    input: Option<PathBuf>,
+    /// Part of the puzzle to solve (solves all solved parts if omitted)
+    #[arg(long)]
+    part: Option<Part>,
+}
+
+/// Placeholder for days without additional command line arguments
+#[derive(Debug, Args)]
+struct NoExtraArgs;
+
+/// Solution for both parts of the puzzle of a day
+///
+/// Days only need to implement the parser and the solvers for the individual parts.
+/// Parts without a solution can rely on the default implementation of [`Solution::part2`],
+/// but should then also restrict [`Solution::PARTS`].
+pub trait Solution {
+    /// Number of the day (1-12)
+    const DAY: u8;
+    /// Parts of the puzzle that have a solution
+    const PARTS: &'static [Part] = &Part::ALL;
+
+    /// Parsed puzzle input
+    type Input: Debug;
+
+    /// Parses the puzzle input
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ParseError`] pointing to the malformed part of the input.
+    fn parse_input(content: &str) -> Result<Self::Input, ParseError>;
+
+    /// Parses the puzzle input of the given kind
+    ///
+    /// The default implementation ignores the kind. Days whose puzzle differs for the sample
+    /// override it.
+    ///
+    /// # Errors
+    ///
+    /// Returns a [`ParseError`] pointing to the malformed part of the input.
+    fn parse_input_of_kind(content: &str, _kind: InputKind) -> Result<Self::Input, ParseError> {
+        Self::parse_input(content)
+    }
+
+    /// Solves part 1 of the puzzle
+    ///
+    /// # Errors
+    ///
+    /// Returns an error message if no solution can be found for the given input.
+    fn part1(input: &Self::Input) -> Result<String, String>;
+
+    /// Solves part 2 of the puzzle
+    ///
+    /// # Errors
+    ///
+    /// Returns an error message if no solution can be found for the given input.
+    /// The default implementation always fails because the part is not solved yet.
+    fn part2(_input: &Self::Input) -> Result<String, String> {
+        Err(format!("Part 2 of day {} is not solved yet.", Self::DAY))
+    }
+
+    /// Parses the puzzle input `content` of the given kind and solves the given part of the puzzle
+    ///
+    /// # Errors
+    ///
+    /// Returns an error message if the input cannot be parsed or the solver fails.
+    fn solve(part: Part, content: &str, kind: InputKind) -> Result<String, String> {
+        let input = Self::parse_input_of_kind(content, kind).map_err(|err| err.to_string())?;
+        Self::solve_part(part, &input)
+    }
+
+    /// Solves the given part of the puzzle for an already parsed input
+    ///
+    /// # Errors
+    ///
+    /// Returns an error message if the solver for the part fails.
+    fn solve_part(part: Part, input: &Self::Input) -> Result<String, String> {
+        match part {
+            Part::One => Self::part1(input),
+            Part::Two => Self::part2(input),
+        }
+    }
+}
+
+/// Runs the solution of a day as command line program and prints the results
+///
+/// The input file defaults to `default_input`, but can be passed as first argument.
+/// Files whose name starts with `sample` are parsed as [`InputKind::Sample`].
+/// With `--part 1` or `--part 2`, only the given part is solved.
+pub fn run_cli<S: Solution>(default_input: &str) {
+    run_cli_with::<S, NoExtraArgs>(default_input, |_, _| Ok(None));
+}
+
+/// Runs the solution of a day as command line program with additional arguments `E`
+///
+/// After parsing the input, `run_extra` gets the additional arguments. If they ask for something
+/// else than the answers of the puzzle, it returns the output to print instead of the answers.
+/// Otherwise it returns `None` and the parts are solved like in [`run_cli`].
+#[expect(clippy::print_stdout, reason = "This is a CLI function.")]
+pub fn run_cli_with<S: Solution, E: Args + Debug>(
+    default_input: &str,
+    run_extra: ExtraHandler<E, S::Input>,
+) {
+    init_logger();
+    let args = DayArgs::<E>::parse();
+    let path = args.input.unwrap_or_else(|| default_input.into());
+    let content = read_input(&path).unwrap_or_else(|msg| exit_with_error(&msg));
+    let input = S::parse_input_of_kind(&content, InputKind::of(&path))
+        .unwrap_or_else(|err| exit_with_error(&format!("Could not parse input! Reason:\n{err}")));
+    info!("Parsed input: {input:?}");
+    match run_extra(&args.extra, &input) {
+        Ok(Some(output)) => {
+            println!("{output}");
+            return;
+        }
+        Ok(None) => {}
+        Err(err) => exit_with_error(&err),
+    }
+    let parts = args
+        .part
+        .map_or_else(|| S::PARTS.to_vec(), |part| vec![part]);
+    for part in parts {
+        match S::solve_part(part, &input) {
+            Ok(answer) => println!("Part {part}: {answer}"),
+            Err(err) => exit_with_error(&format!("Could not solve part {part}! Reason:\n{err}")),
+        }
+    }
+}
@@ -0,0 +1,36 @@
+//! Helpers for the tests of the days, only compiled with the `test-util` feature
+//!
+//! Tests that compare a fast algorithm against brute force need many different inputs, which
+//! should still be the same in every run so that failures can be reproduced.
+
+/// Pseudo-random number generator for reproducible test inputs
+///
+/// This is a linear congruential generator with the constants of Knuth's MMIX. Its low bits are
+/// not very random, so only the high bits of the state are handed out.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Lcg {
+    /// Current state, which is the last generated number
+    state: u64,
+}
+
+impl Lcg {
+    /// Next number below `bound`, or 0 if `bound` is 0
+    pub fn below(&mut self, bound: u64) -> u64 {
+        (self.next_u64() >> 33_u32).checked_rem(bound).unwrap_or(0)
+    }
+
+    /// Starts a generator that always produces the same numbers for the same `seed`
+    #[must_use]
+    pub const fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+
+    /// Advances the state and returns it
+    pub const fn next_u64(&mut self) -> u64 {
+        self.state = self
+            .state
+            .wrapping_mul(6_364_136_223_846_793_005)
+            .wrapping_add(1_442_695_040_888_963_407);
+        self.state
+    }
+}
@@ -0,0 +1,129 @@
+//! Disjoint sets of elements that can be merged, also known as union-find
+//!
+//! Elements are the indices `0..len`. Merging uses union by rank and lookups compress the path
+//! to the representative, so both take nearly constant time.
+
+/// Partition of the elements `0..len` into disjoint sets
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct UnionFind {
+    /// Parent of each element, which is the element itself for the representative of a set
+    parents: Vec<usize>,
+    /// Upper bound for the height of the tree below each representative
+    ranks: Vec<u8>,
+    /// Number of sets
+    set_count: usize,
+    /// Number of elements in the set of each representative
+    sizes: Vec<usize>,
+}
+
+impl UnionFind {
+    /// Finds the representative of the set containing `element`
+    ///
+    /// Elements that do not exist are their own representative.
+    pub fn find(&mut self, element: usize) -> usize {
+        let mut root = element;
+        while let Some(&parent) = self.parents.get(root)
+            && parent != root
+        {
+            root = parent;
+        }
+        // let every element on the way point directly to the representative
+        let mut current = element;
+        while let Some(parent) = self.parents.get_mut(current)
+            && *parent != root
+        {
+            current = *parent;
+            *parent = root;
+        }
+        root
+    }
+
+    /// Returns whether there are no elements
+    #[must_use]
+    pub const fn is_empty(&self) -> bool {
+        self.parents.is_empty()
+    }
+
+    /// Number of elements
+    #[must_use]
+    pub const fn len(&self) -> usize {
+        self.parents.len()
+    }
+
+    /// Creates `len` sets that contain one element each
+    #[must_use]
+    pub fn new(len: usize) -> Self {
+        Self {
+            parents: (0..len).collect(),
+            ranks: vec![0; len],
+            set_count: len,
+            sizes: vec![1; len],
+        }
+    }
+
+    /// Returns whether two elements are in the same set
+    pub fn same_set(&mut self, first: usize, second: usize) -> bool {
+        self.find(first) == self.find(second)
+    }
+
+    /// Number of disjoint sets
+    #[must_use]
+    pub const fn set_count(&self) -> usize {
+        self.set_count
+    }
+
+    /// Number of elements in the set containing `element`, which is 0 if the element does not exist
+    pub fn set_size(&mut self, element: usize) -> usize {
+        let root = self.find(element);
+        self.sizes.get(root).copied().unwrap_or(0)
+    }
+
+    /// Sizes of all sets in descending order
+    #[must_use]
+    pub fn set_sizes(&self) -> Vec<usize> {
+        let mut sizes = self
+            .parents
+            .iter()
+            .enumerate()
+            .filter(|&(element, &parent)| element == parent)
+            .filter_map(|(root, _)| self.sizes.get(root).copied())
+            .collect::<Vec<usize>>();
+        sizes.sort_unstable_by(|first, second| second.cmp(first));
+        sizes
+    }
+
+    /// Merges the sets containing `first` and `second`
+    ///
+    /// Returns `false` if both are already in the same set or one of them does not exist.
+    pub fn union(&mut self, first: usize, second: usize) -> bool {
+        let (first_root, second_root) = (self.find(first), self.find(second));
+        if first_root == second_root {
+            return false;
+        }
+        let (Some(&first_rank), Some(&second_rank)) =
+            (self.ranks.get(first_root), self.ranks.get(second_root))
+        else {
+            return false;
+        };
+        // attach the lower tree below the higher one so that the trees stay flat
+        let (root, child) = if first_rank < second_rank {
+            (second_root, first_root)
+        } else {
+            (first_root, second_root)
+        };
+        if let Some(parent) = self.parents.get_mut(child) {
+            *parent = root;
+        }
+        if first_rank == second_rank
+            && let Some(rank) = self.ranks.get_mut(root)
+        {
+            *rank = rank.saturating_add(1);
+        }
+        let child_size = self.sizes.get(child).copied().unwrap_or(0);
+        if let Some(size) = self.sizes.get_mut(root) {
+            *size = size.saturating_add(child_size);
+        }
+        self.set_count = self.set_count.saturating_sub(1);
+        true
+    }
+}
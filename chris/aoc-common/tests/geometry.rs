@@ -0,0 +1,67 @@
+//! Tests for points with signed and floating-point coordinates
+
+use aoc_common::geometry::{Point2, Point3};
+
+/// Checks that negative coordinates and whitespace around the commas are accepted
+#[test]
+fn parses_signed_coordinates() {
+    assert_eq!(Point2::<i64>::parse(0, "-3, 4"), Ok(Point2::new(-3, 4)));
+    assert_eq!(
+        Point3::<i64>::parse(0, " 1 ,-2,  -30 "),
+        Ok(Point3::new(1, -2, -30))
+    );
+    assert_eq!(
+        Point2::<f64>::parse(0, "0.5,-1.25"),
+        Ok(Point2::new(0.5_f64, -1.25_f64))
+    );
+    assert_eq!(Point3::new(-1_i64, 2, 0).to_string(), "-1,2,0");
+}
+
+/// Checks that lines with the wrong number of coordinates or bad numbers are rejected
+#[test]
+fn rejects_malformed_points() {
+    let missing =
+        Point3::<i64>::parse(4, "1,2").expect_err("Two coordinates are not a point in space.");
+    assert_eq!(missing.expected(), "three comma-separated coordinates");
+    assert_eq!(missing.line(), Some(5));
+    let invalid = Point2::<i64>::parse(0, "1,x").expect_err("A letter is not a coordinate.");
+    assert_eq!(invalid.columns(), Some(2..3));
+}
+
+/// Checks the vector operations and distances
+#[test]
+#[expect(
+    clippy::decimal_literal_representation,
+    reason = "Small test values are more readable in decimal notation."
+)]
+fn vector_arithmetic() {
+    let first = Point3::new(1_i64, -2, 3);
+    let second = Point3::new(-4_i64, 5, 6);
+    assert_eq!(first.checked_add(&second), Some(Point3::new(-3, 3, 9)));
+    assert_eq!(first.checked_sub(&second), Some(Point3::new(5, -7, -3)));
+    assert_eq!(first.checked_scale(-2), Some(Point3::new(-2, 4, -6)));
+    assert_eq!(first.dot(&second), Some(4));
+    assert_eq!(first.cross(&second), Some(Point3::new(-27, -18, -3)));
+    assert_eq!(first.manhattan_distance(&second), Some(15));
+    assert_eq!(first.squared_distance(&second), Some(83));
+    let right = Point2::new(1_i64, 0);
+    let up = Point2::new(0_i64, 1);
+    assert_eq!(right.cross(&up), Some(1));
+    assert_eq!(up.cross(&right), Some(-1));
+    assert_eq!(right.manhattan_distance(&Point2::new(-2, -3)), Some(6));
+}
+
+/// Checks that overflows and infinite results are reported instead of wrapping
+#[test]
+fn overflow_is_checked() {
+    let far = Point2::new(i64::MAX, i64::MIN);
+    assert_eq!(far.checked_add(&Point2::new(1, 0)), None);
+    assert_eq!(far.manhattan_distance(&Point2::default()), None);
+    assert_eq!(Point3::new(i64::MIN, 0, 0).checked_scale(-1), None);
+    let huge = Point2::new(f64::MAX, 0.0_f64);
+    assert_eq!(huge.checked_scale(2.0_f64), None);
+    assert_eq!(
+        huge.checked_scale(-1.0_f64),
+        Some(Point2::new(f64::MIN, -0.0_f64))
+    );
+}
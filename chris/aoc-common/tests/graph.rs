@@ -0,0 +1,378 @@
+//! Tests for traversals and orderings of directed graphs
+
+use aoc_common::graph::{ExportFormat, Graph, Highlight, NodeId, NodeStyle};
+
+/// Builds a graph from a list of edges between named nodes
+fn graph_from_edges(edges: &[(&str, &str)]) -> Graph {
+    let mut graph = Graph::new();
+    for &(from, to) in edges {
+        let from_id = graph.insert(from);
+        let to_id = graph.insert(to);
+        graph.add_edge(from_id, to_id);
+    }
+    graph
+}
+
+/// Converts node IDs back into names
+fn names(graph: &Graph, nodes: &[NodeId]) -> Vec<String> {
+    nodes
+        .iter()
+        .map(|&node| graph.name(node).unwrap_or_default().to_owned())
+        .collect()
+}
+
+/// Builds a graph from a list of weighted edges between named nodes
+fn weighted_graph(edges: &[(&str, &str, u64)]) -> Graph {
+    let mut graph = Graph::new();
+    for &(from, to, weight) in edges {
+        let from_id = graph.insert(from);
+        let to_id = graph.insert(to);
+        graph.add_weighted_edge(from_id, to_id, weight);
+    }
+    graph
+}
+
+/// Diamond `a -> {b, c} -> d` with an extra node `e` that only points to `d`
+fn diamond() -> Graph {
+    graph_from_edges(&[("a", "b"), ("a", "c"), ("b", "d"), ("c", "d"), ("e", "d")])
+}
+
+/// Chain of `length` diamonds `n{i} -> {u{i}, l{i}} -> n{i+1}`, which has 2^length paths
+fn diamond_chain(length: usize) -> Graph {
+    let mut edges = Vec::new();
+    for idx in 0..length {
+        let (from, to) = (format!("n{idx}"), format!("n{}", idx.saturating_add(1)));
+        let (upper, lower) = (format!("u{idx}"), format!("l{idx}"));
+        edges.extend([
+            (from.clone(), upper.clone()),
+            (from, lower.clone()),
+            (upper, to.clone()),
+            (lower, to),
+        ]);
+    }
+    let edge_refs = edges
+        .iter()
+        .map(|edge| (edge.0.as_str(), edge.1.as_str()))
+        .collect::<Vec<(&str, &str)>>();
+    graph_from_edges(&edge_refs)
+}
+
+/// Checks that names are interned
+#[test]
+fn names_are_interned() {
+    let mut graph = diamond();
+    assert_eq!(graph.len(), 5);
+    assert_eq!(graph.insert("b"), 1);
+    assert_eq!(graph.len(), 5);
+    assert_eq!(graph.id("e"), Some(4));
+    assert_eq!(graph.id("z"), None);
+    assert_eq!(names(&graph, graph.incoming(3)), vec!["b", "c", "e"]);
+}
+
+/// Checks the visiting order of breadth-first and depth-first search
+#[test]
+fn traversal_orders() {
+    let graph = graph_from_edges(&[("a", "b"), ("a", "c"), ("b", "d"), ("c", "e")]);
+    assert_eq!(names(&graph, &graph.bfs(0)), vec!["a", "b", "c", "d", "e"]);
+    assert_eq!(names(&graph, &graph.dfs(0)), vec!["a", "b", "d", "c", "e"]);
+}
+
+/// Checks forward and reverse reachability
+#[test]
+fn reachability() {
+    let graph = diamond();
+    assert_eq!(
+        graph.reachable_from(1),
+        vec![false, true, false, true, false]
+    );
+    assert_eq!(graph.reaching(3), vec![true; 5]);
+    assert_eq!(graph.reaching(2), vec![true, false, true, false, false]);
+}
+
+/// Checks that topological orders respect all edges and that cycles are rejected
+#[test]
+fn topological_order() {
+    let graph = diamond();
+    let order = graph.topological_order().unwrap_or_default();
+    let position = |node: NodeId| order.iter().position(|&other| other == node);
+    assert_eq!(order.len(), graph.len());
+    for node in graph.ids() {
+        for &successor in graph.outgoing(node) {
+            assert!(
+                position(node) < position(successor),
+                "Edge points backwards!"
+            );
+        }
+    }
+    let cyclic = graph_from_edges(&[("a", "b"), ("b", "c"), ("c", "a")]);
+    assert_eq!(cyclic.topological_order(), None);
+}
+
+/// Checks that waypoints are required in any order and that the counts stay exact
+#[test]
+fn count_paths_via_waypoints() {
+    let graph = diamond();
+    let node = |name: &str| graph.id(name).unwrap_or_default();
+    let (top, bottom) = (node("a"), node("d"));
+    assert_eq!(graph.count_paths(top, bottom, &[]), Ok(2));
+    assert_eq!(graph.count_paths(top, bottom, &[node("b")]), Ok(1));
+    assert_eq!(graph.count_paths(top, bottom, &[bottom, top]), Ok(2));
+    assert_eq!(
+        graph.count_paths(top, bottom, &[node("b"), node("c")]),
+        Ok(0)
+    );
+    assert_eq!(graph.count_paths(bottom, top, &[]), Ok(0));
+
+    // a chain of 100 diamonds has 2^100 paths, far more than could be enumerated
+    let chain = diamond_chain(100);
+    let chain_node = |name: &str| chain.id(name).unwrap_or_default();
+    let (first, last) = (chain_node("n0"), chain_node("n100"));
+    assert_eq!(chain.count_paths(first, last, &[]), Ok(1_u128 << 100_u32));
+    assert_eq!(
+        chain.count_paths(first, last, &[chain_node("u7"), chain_node("l3")]),
+        Ok(1_u128 << 98_u32)
+    );
+
+    // only the visited subsets of the waypoints are stored, so the maximum of 16 stays cheap
+    #[expect(
+        clippy::decimal_literal_representation,
+        reason = "The number of waypoints is more readable in decimal notation."
+    )]
+    let upper = (1_usize..=16)
+        .map(|idx| chain_node(&format!("u{idx}")))
+        .collect::<Vec<NodeId>>();
+    assert_eq!(chain.count_paths(first, last, &upper), Ok(1_u128 << 84_u32));
+    let too_many = [upper.as_slice(), &[chain_node("l20")]].concat();
+    assert!(
+        chain.count_paths(first, last, &too_many).is_err(),
+        "More than 16 waypoints should be rejected!"
+    );
+}
+
+/// Checks the paths through each node against counting the paths via that node
+#[test]
+fn count_paths_through_each_node() {
+    let chain = diamond_chain(20);
+    let node = |name: &str| chain.id(name).unwrap_or_default();
+    let (first, last) = (node("n0"), node("n20"));
+    for via in [vec![], vec![node("u3")], vec![node("l5"), node("u2")]] {
+        let through = chain
+            .count_paths_through(first, last, &via)
+            .expect("the graph has no cycles");
+        for id in chain.ids() {
+            let with_node = [via.as_slice(), &[id]].concat();
+            assert_eq!(
+                through.get(id).copied(),
+                chain.count_paths(first, last, &with_node).ok()
+            );
+        }
+    }
+
+    // the node itself does not count as an additional waypoint
+    #[expect(
+        clippy::decimal_literal_representation,
+        reason = "The number of waypoints is more readable in decimal notation."
+    )]
+    let upper = (1_usize..=16)
+        .map(|idx| node(&format!("u{idx}")))
+        .collect::<Vec<NodeId>>();
+    let through = chain
+        .count_paths_through(first, last, &upper)
+        .expect("16 waypoints are allowed");
+    assert_eq!(through.get(last), Some(&(1_u128 << 4_u32)));
+    assert_eq!(through.get(node("l0")), Some(&(1_u128 << 3_u32)));
+    assert_eq!(through.get(node("l1")), Some(&0));
+}
+
+/// Checks that path counting rejects cycles
+#[test]
+fn count_paths_rejects_cycles() {
+    let cyclic = graph_from_edges(&[("a", "b"), ("b", "a")]);
+    assert!(
+        cyclic.count_paths(0, 1, &[]).is_err(),
+        "Cycles should be rejected!"
+    );
+}
+
+/// Checks that strongly connected components group the nodes on each cycle
+#[test]
+fn cycles_are_detected() {
+    let graph = graph_from_edges(&[
+        ("a", "b"),
+        ("b", "c"),
+        ("c", "b"),
+        ("c", "d"),
+        ("d", "d"),
+        ("d", "e"),
+    ]);
+    let components = graph
+        .strongly_connected_components()
+        .iter()
+        .map(|component| names(&graph, component))
+        .collect::<Vec<Vec<String>>>();
+    assert_eq!(
+        components,
+        vec![vec!["a"], vec!["b", "c"], vec!["d"], vec!["e"]]
+    );
+    assert_eq!(graph.cycles().len(), 2);
+    assert_eq!(graph.describe_cycles(), "{b, c}, {d}");
+    assert!(diamond().cycles().is_empty(), "A diamond has no cycles!");
+}
+
+/// Checks that simple paths match all paths without cycles and stay finite with cycles
+#[test]
+fn count_simple_paths() {
+    let graph = diamond();
+    let node = |name: &str| graph.id(name).unwrap_or_default();
+    assert_eq!(graph.count_simple_paths(node("a"), node("d"), &[]), Ok(2));
+    assert_eq!(
+        graph.count_simple_paths(node("a"), node("d"), &[node("c")]),
+        Ok(1)
+    );
+    let cyclic = graph_from_edges(&[("a", "b"), ("b", "a"), ("b", "c"), ("a", "c")]);
+    let cyclic_node = |name: &str| cyclic.id(name).unwrap_or_default();
+    let error = cyclic
+        .count_paths(cyclic_node("a"), cyclic_node("c"), &[])
+        .err()
+        .unwrap_or_default();
+    assert!(
+        error.contains("{a, b}"),
+        "Error should list the cycle: {error}"
+    );
+    assert_eq!(
+        cyclic.count_simple_paths(cyclic_node("a"), cyclic_node("c"), &[]),
+        Ok(2)
+    );
+}
+
+/// Checks that simple paths are enumerated lazily and filtered by waypoints
+#[test]
+fn simple_paths_are_lazy() {
+    let graph = diamond();
+    let node = |name: &str| graph.id(name).unwrap_or_default();
+    let paths = graph
+        .simple_paths(node("a"), node("d"), &[node("c")])
+        .expect("one waypoint is allowed")
+        .map(|path| names(&graph, &path))
+        .collect::<Vec<Vec<String>>>();
+    assert_eq!(paths, vec![vec!["a", "c", "d"]]);
+    assert_eq!(
+        graph
+            .simple_paths(node("d"), node("a"), &[])
+            .expect("no waypoints are allowed")
+            .count(),
+        0
+    );
+
+    // taking the first paths of a chain with 2^100 paths must not enumerate all of them
+    let chain = diamond_chain(100);
+    let chain_node = |name: &str| chain.id(name).unwrap_or_default();
+    let first_paths = chain
+        .simple_paths(chain_node("n0"), chain_node("n100"), &[chain_node("l0")])
+        .expect("one waypoint is allowed")
+        .take(3)
+        .collect::<Vec<Vec<NodeId>>>();
+    assert_eq!(first_paths.len(), 3);
+    assert!(
+        first_paths
+            .iter()
+            .all(|path| path.len() == 201 && path.contains(&chain_node("l0")))
+    );
+}
+
+/// Checks the DOT and Mermaid exports including highlights, notes and escaping
+#[test]
+fn export_formats() {
+    let graph = graph_from_edges(&[("a", "say \"hi\"")]);
+    let style = |node: NodeId| NodeStyle {
+        highlight: (node == 0).then_some(Highlight::Start),
+        note: (node == 1).then(|| "2 paths".to_owned()),
+    };
+    assert_eq!(
+        graph.export(ExportFormat::Dot, style),
+        "digraph {\n    n0 [label=\"a\", style=filled, fillcolor=\"#90ee90\"];\n    \
+         n1 [label=\"say \\\"hi\\\"\\n2 paths\"];\n    n0 -> n1;\n}\n"
+    );
+    let mermaid = graph.export(ExportFormat::Mermaid, style);
+    assert!(
+        mermaid.starts_with(
+            "flowchart TD\n    n0[\"a\"]\n    n1[\"say #quot;hi#quot;<br>2 paths\"]\n    n0 --> n1\n"
+        ),
+        "Unexpected Mermaid output:\n{mermaid}"
+    );
+    assert!(
+        mermaid.ends_with("    class n0 startNode\n"),
+        "Start node should be highlighted:\n{mermaid}"
+    );
+}
+
+/// Checks shortest, longest and k-shortest paths on a weighted graph
+#[test]
+fn weighted_paths() {
+    let graph = weighted_graph(&[
+        ("s", "a", 5),
+        ("s", "b", 1),
+        ("b", "a", 1),
+        ("a", "t", 1),
+        ("b", "c", 2),
+        ("c", "t", 1),
+        ("b", "t", 9),
+        ("s", "t", 20),
+    ]);
+    let source = graph.id("s").unwrap_or_default();
+    let target = graph.id("t").unwrap_or_default();
+    let shortest = graph
+        .shortest_path(source, target)
+        .expect("there is a path");
+    assert_eq!(shortest.length, 3);
+    assert_eq!(names(&graph, &shortest.nodes), vec!["s", "b", "a", "t"]);
+    assert_eq!(graph.path_length(&shortest.nodes), Some(3));
+    let longest = graph
+        .longest_path(source, target)
+        .expect("the graph has no cycles")
+        .expect("there is a path");
+    assert_eq!(longest.length, 20);
+    assert_eq!(names(&graph, &longest.nodes), vec!["s", "t"]);
+    let k_shortest = graph.k_shortest_paths(source, target, 10);
+    assert_eq!(
+        k_shortest
+            .iter()
+            .map(|path| path.length)
+            .collect::<Vec<u64>>(),
+        vec![3, 4, 6, 10, 20]
+    );
+    assert_eq!(
+        names(
+            &graph,
+            &k_shortest.get(1).expect("there is a second path").nodes
+        ),
+        vec!["s", "b", "c", "t"]
+    );
+    assert_eq!(graph.k_shortest_paths(source, target, 2).len(), 2);
+    assert_eq!(graph.k_shortest_paths(source, target, 0), vec![]);
+    assert_eq!(graph.shortest_path(target, source), None);
+    assert_eq!(graph.k_shortest_paths(target, source, 3), vec![]);
+}
+
+/// Checks that shortest paths work with cycles but longest paths do not
+#[test]
+fn weighted_paths_with_cycles() {
+    let graph = weighted_graph(&[("a", "b", 2), ("b", "a", 1), ("b", "c", 4), ("a", "c", 10)]);
+    let shortest = graph.shortest_path(0, 2).expect("there is a path");
+    assert_eq!(names(&graph, &shortest.nodes), vec!["a", "b", "c"]);
+    assert_eq!(
+        graph
+            .k_shortest_paths(0, 2, 5)
+            .iter()
+            .map(|path| path.length)
+            .collect::<Vec<u64>>(),
+        vec![6, 10]
+    );
+    assert!(
+        graph
+            .longest_path(0, 2)
+            .err()
+            .unwrap_or_default()
+            .contains("{a, b}")
+    );
+}
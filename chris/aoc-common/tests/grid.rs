@@ -0,0 +1,101 @@
+//! Tests for indexing, views and transformations of grids
+
+use aoc_common::grid::Grid;
+
+/// Grid with two rows and three columns
+///
+/// ```text
+/// 1 2 3
+/// 4 5 6
+/// ```
+fn two_by_three() -> Grid<u8> {
+    Grid::from_rows_padded(vec![vec![1, 2, 3], vec![4, 5, 6]], &0)
+}
+
+/// Checks that positions outside of the grid are rejected, including negative ones
+#[test]
+fn access_is_bounds_checked() {
+    let grid = two_by_three();
+    assert_eq!(grid.get(1, 2), Some(&6));
+    assert_eq!(grid.get(2, 0), None);
+    assert_eq!(grid.get(0, 3), None);
+    assert_eq!(grid.get_signed(-1, 0), None);
+    assert_eq!(grid.get_signed(0, 1), Some(&2));
+}
+
+/// Checks that neighbors at the border are cut off
+#[test]
+fn neighbors_stay_inside() {
+    let grid = two_by_three();
+    let orthogonal = grid
+        .neighbors4(0, 0)
+        .map(|(_, &val)| val)
+        .collect::<Vec<u8>>();
+    assert_eq!(orthogonal, vec![2, 4]);
+    let surrounding = grid
+        .neighbors8(0, 1)
+        .map(|(_, &val)| val)
+        .collect::<Vec<u8>>();
+    assert_eq!(surrounding, vec![1, 3, 4, 5, 6]);
+}
+
+/// Checks the row and column views
+#[test]
+fn rows_and_columns() {
+    let grid = two_by_three();
+    assert_eq!(grid.row(1), Some([4, 5, 6].as_slice()));
+    assert_eq!(grid.row(2), None);
+    assert_eq!(grid.column(1).copied().collect::<Vec<u8>>(), vec![2, 5]);
+    assert_eq!(grid.column(3).count(), 0);
+    assert_eq!(grid.iter_rows().count(), 2);
+}
+
+/// Checks transposing, rotating and flipping
+#[test]
+fn transformations() {
+    let grid = two_by_three();
+    let rows = |transformed: &Grid<u8>| {
+        transformed
+            .iter_rows()
+            .map(<[u8]>::to_vec)
+            .collect::<Vec<Vec<u8>>>()
+    };
+    assert_eq!(
+        rows(&grid.transposed()),
+        vec![vec![1, 4], vec![2, 5], vec![3, 6]]
+    );
+    assert_eq!(
+        rows(&grid.rotated_clockwise()),
+        vec![vec![4, 1], vec![5, 2], vec![6, 3]]
+    );
+    assert_eq!(
+        rows(&grid.flipped_horizontally()),
+        vec![vec![3, 2, 1], vec![6, 5, 4]]
+    );
+    assert_eq!(
+        rows(&grid.flipped_vertically()),
+        vec![vec![4, 5, 6], vec![1, 2, 3]]
+    );
+    let full_turn = grid
+        .rotated_clockwise()
+        .rotated_clockwise()
+        .rotated_clockwise()
+        .rotated_clockwise();
+    assert_eq!(full_turn, grid);
+}
+
+/// Checks that parsing reports unknown characters and rows of different length
+#[test]
+fn parse_reports_malformed_rows() {
+    let parse_cell = |chr: char| chr.to_digit(10);
+    let grid = Grid::parse("12\n34\n", "a digit", parse_cell);
+    assert_eq!(grid.map(|parsed| parsed.columns()), Ok(2));
+    let unknown = Grid::parse("12\n3x\n", "a digit", parse_cell).map_err(|err| err.columns());
+    assert_eq!(unknown, Err(Some(1..2)));
+    let ragged = Grid::parse("12\n345\n", "a digit", parse_cell).map_err(|err| err.line());
+    assert_eq!(ragged, Err(Some(2)));
+    assert!(
+        Grid::parse("", "a digit", parse_cell).is_err(),
+        "Empty grids are not allowed!"
+    );
+}
@@ -0,0 +1,89 @@
+//! Tests for the k-d tree against brute force over all points
+
+use core::iter;
+
+use aoc_common::geometry::Point3;
+use aoc_common::kd_tree::{KdTree, Neighbor, Pair};
+use aoc_common::metric::{Euclidean, Metric as _, Routing};
+use aoc_common::test_util::Lcg;
+
+/// All metrics that can be chosen on the command line
+const ROUTINGS: [Routing; 3] = [Routing::Chebyshev, Routing::Euclidean, Routing::Manhattan];
+
+/// Generates pseudo-random points in a small cube, so that many distances are equal
+fn random_points(count: usize) -> Vec<Point3<i64>> {
+    let mut rng = Lcg::new(12_345);
+    let mut next = || rng.next_u64() >> 59_u32;
+    iter::repeat_with(|| format!("{},{},{}", next(), next(), next()))
+        .take(count)
+        .enumerate()
+        .filter_map(|(idx, line)| Point3::parse(idx, &line).ok())
+        .collect()
+}
+
+/// Lists the distances of all points to `target`, ordered like the queries of the tree
+fn all_neighbors(points: &[Point3<i64>], target: Point3<i64>, metric: Routing) -> Vec<Neighbor> {
+    let mut neighbors = points
+        .iter()
+        .enumerate()
+        .map(|(idx, point)| (metric.distance(&target, point), idx))
+        .collect::<Vec<Neighbor>>();
+    neighbors.sort_unstable();
+    neighbors
+}
+
+/// Checks nearest-neighbor and radius queries for every metric
+#[test]
+fn queries_match_brute_force() {
+    let points = random_points(300);
+    assert_eq!(points.len(), 300);
+    for metric in ROUTINGS {
+        let tree = KdTree::new(&points, metric);
+        for target in points.iter().step_by(5) {
+            let expected = all_neighbors(&points, *target, metric);
+            for count in [1, 5, 40, 300, 500] {
+                let nearest = tree.nearest(target, count);
+                assert_eq!(
+                    nearest,
+                    expected
+                        .iter()
+                        .copied()
+                        .take(count)
+                        .collect::<Vec<Neighbor>>()
+                );
+            }
+            for radius in [0, 10, 50] {
+                let within = tree.within(target, radius);
+                let inside = expected
+                    .iter()
+                    .copied()
+                    .filter(|&(dist, _)| dist <= radius)
+                    .collect::<Vec<Neighbor>>();
+                assert_eq!(within, inside);
+            }
+        }
+    }
+    assert!(
+        KdTree::new(&[], Euclidean)
+            .nearest(points.first().expect("there are points"), 3)
+            .is_empty()
+    );
+}
+
+/// Checks that the lazy pairs come in the same order as sorting all pairs for every metric
+#[test]
+fn pairs_match_brute_force() {
+    let points = random_points(120);
+    for metric in ROUTINGS {
+        let mut expected = Vec::new();
+        for (first_idx, first) in points.iter().enumerate() {
+            for (second_idx, second) in points.iter().enumerate().skip(first_idx.saturating_add(1))
+            {
+                expected.push((metric.distance(first, second), first_idx, second_idx));
+            }
+        }
+        expected.sort_unstable();
+        let tree = KdTree::new(&points, metric);
+        assert_eq!(tree.pairs().collect::<Vec<Pair>>(), expected);
+    }
+}
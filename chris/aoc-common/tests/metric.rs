@@ -0,0 +1,18 @@
+//! Tests for the ways to measure distances between points in space
+
+use aoc_common::geometry::Point3;
+use aoc_common::metric::{Chebyshev, Euclidean, Manhattan, Metric as _, Routing};
+
+/// Checks the distances of each metric
+#[test]
+fn metric_distances() {
+    let first = Point3::<i64>::parse(0, "1,2,3").expect("the point is valid");
+    let second = Point3::<i64>::parse(1, "4,8,5").expect("the point is valid");
+    assert_eq!(Euclidean.distance(&first, &second), 49);
+    assert_eq!(Manhattan.distance(&first, &second), 11);
+    assert_eq!(Chebyshev.distance(&first, &second), 6);
+    assert_eq!(Routing::Manhattan.distance(&first, &second), 11);
+    assert!((Euclidean.length(49) - 7.0).abs() < f64::EPSILON);
+    assert_eq!(Euclidean.distance_for_length(5), 25);
+    assert_eq!(Chebyshev.distance_for_length(5), 5);
+}
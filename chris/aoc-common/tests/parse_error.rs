@@ -0,0 +1,56 @@
+//! Tests for the rendering of parse errors
+
+use aoc_common::input::{ParseError, parse_token};
+
+/// Checks that the carets point to the offending token
+#[test]
+fn renders_caret_below_token() {
+    let line = "L6x8";
+    let err = ParseError::at_token(2, line, line.get(1..).unwrap_or_default(), "a number");
+    assert_eq!(err.line(), Some(3));
+    assert_eq!(err.columns(), Some(1..4));
+    assert_eq!(
+        err.to_string(),
+        "line 3, column 2: expected a number\n  |\n3 | L6x8\n  |  ^^^"
+    );
+}
+
+/// Checks that the gutter grows with the number of digits of the line number
+#[test]
+fn renders_wide_line_numbers() {
+    let err = ParseError::at(99, "1,2", 1..2, "a digit");
+    assert_eq!(
+        err.to_string(),
+        "line 100, column 2: expected a digit\n    |\n100 | 1,2\n    |  ^"
+    );
+}
+
+/// Checks that tokens that are not part of the line mark the whole line
+#[test]
+fn foreign_token_marks_whole_line() {
+    let err = ParseError::at_token(0, "abc", "xyz", "something else");
+    assert_eq!(err.columns(), Some(0..3));
+}
+
+/// Checks that errors without a location only show what was expected
+#[test]
+fn renders_error_without_location() {
+    let err = ParseError::in_input("at least one line");
+    assert_eq!(err.line(), None);
+    assert_eq!(err.to_string(), "expected at least one line");
+}
+
+/// Checks that `parse_token` reports the position of the token on failure
+#[test]
+fn parse_token_reports_position() {
+    let line = "12,ab";
+    let token = line.get(3..).unwrap_or_default();
+    assert_eq!(
+        parse_token::<u8>(0, line, line.get(..2).unwrap_or_default(), "a number"),
+        Ok(12)
+    );
+    assert_eq!(
+        parse_token::<u8>(0, line, token, "a number").map_err(|err| err.columns()),
+        Err(Some(3..5))
+    );
+}
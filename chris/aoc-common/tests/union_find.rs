@@ -0,0 +1,43 @@
+//! Tests for merging disjoint sets
+
+use aoc_common::union_find::UnionFind;
+
+/// Checks merging, lookups and the set sizes
+#[test]
+fn sets_are_merged() {
+    let mut sets = UnionFind::new(6);
+    assert_eq!(sets.len(), 6);
+    assert_eq!(sets.set_count(), 6);
+    assert!(sets.union(0, 1));
+    assert!(sets.union(2, 3));
+    assert!(sets.union(1, 3));
+    assert!(!sets.union(0, 2));
+    assert!(sets.same_set(0, 3));
+    assert!(!sets.same_set(0, 4));
+    assert_eq!(sets.set_size(2), 4);
+    assert_eq!(sets.set_count(), 3);
+    assert_eq!(sets.set_sizes(), vec![4, 1, 1]);
+}
+
+/// Checks that elements outside of the structure are ignored
+#[test]
+fn missing_elements() {
+    let mut sets = UnionFind::new(2);
+    assert_eq!(sets.find(5), 5);
+    assert!(!sets.union(0, 5));
+    assert_eq!(sets.set_size(5), 0);
+    assert_eq!(sets.set_count(), 2);
+    assert!(UnionFind::new(0).is_empty());
+}
+
+/// Checks that a long chain of merges ends up in a single set
+#[test]
+fn long_chain() {
+    let mut sets = UnionFind::new(1000);
+    for element in 1..1000 {
+        assert!(sets.union(element - 1, element));
+    }
+    assert_eq!(sets.set_count(), 1);
+    assert_eq!(sets.set_sizes(), vec![1000]);
+    assert_eq!(sets.find(999), sets.find(0));
+}
@@ -0,0 +1,131 @@
+//! The `bench` command, which measures how long the solutions take to parse and solve
+
+use std::fs;
+use std::path::PathBuf;
+
+use aoc_common::bench::Timings;
+use aoc_common::read_input;
+use aoc_common::solution::Part;
+use clap::Args;
+use serde::Serialize;
+
+use crate::{DAYS, Day, default_input_path, find_day, input_kind};
+
+/// Arguments for the `bench` command
+#[derive(Debug, Args)]
+pub struct BenchArgs {
+    /// Day to benchmark (1-12), benchmarks all days if omitted
+    #[arg(value_parser = clap::value_parser!(u8).range(1..=12))]
+    day: Option<u8>,
+    /// Number of runs of each phase
+    #[arg(long, default_value_t = 10, value_parser = clap::value_parser!(u32).range(1..))]
+    iterations: u32,
+    /// JSON file to save the results to
+    #[arg(long, default_value = "bench.json")]
+    output: PathBuf,
+    /// Use the sample inputs from the exercise descriptions instead of `input.txt`
+    #[arg(long)]
+    sample: bool,
+}
+
+/// Timings of a single phase of a day, in the form saved to the JSON file
+#[derive(Debug, Serialize)]
+struct PhaseRecord {
+    /// Number of the day
+    day: u8,
+    /// Number of runs of the phase
+    iterations: usize,
+    /// Wall time of the slowest run in nanoseconds
+    max_ns: u128,
+    /// Wall time of the median run in nanoseconds
+    median_ns: u128,
+    /// Wall time of the fastest run in nanoseconds
+    min_ns: u128,
+    /// Measured phase (`parse`, `part 1` or `part 2`)
+    phase: String,
+}
+
+/// Results of the `bench` command, in the form saved to the JSON file
+#[derive(Debug, Serialize)]
+struct BenchReport {
+    /// Timings of all phases that could be measured
+    phases: Vec<PhaseRecord>,
+    /// Whether the sample inputs were used
+    sample: bool,
+}
+
+/// Name of a phase and its timings or the error that occurred while measuring it
+type PhaseResult = (String, Result<Timings, String>);
+
+/// Measures the parser on the input of part 1 and the solvers of all parts on their inputs
+fn bench_day(day: &Day, iterations: usize, sample: bool) -> Vec<PhaseResult> {
+    let number = day.number;
+    let parse_path = default_input_path(number, Part::One, sample);
+    let mut phases = vec![(
+        "parse".to_owned(),
+        read_input(&parse_path).and_then(|content| {
+            (day.bench_parse)(&content, input_kind(&parse_path, sample), iterations)
+        }),
+    )];
+    for &part in day.parts {
+        let path = default_input_path(number, part, sample);
+        phases.push((
+            format!("part {part}"),
+            read_input(&path).and_then(|content| {
+                (day.bench_part)(part, &content, input_kind(&path, sample), iterations)
+            }),
+        ));
+    }
+    phases
+}
+
+/// Runs the `bench` command, reporting failing phases without aborting
+///
+/// # Errors
+///
+/// Returns an error message if the day does not exist or the results cannot be saved.
+#[expect(
+    clippy::print_stdout,
+    clippy::print_stderr,
+    reason = "This is a CLI function."
+)]
+pub fn run_bench(args: &BenchArgs) -> Result<(), String> {
+    let days = match args.day {
+        Some(number) => vec![find_day(number)?],
+        None => DAYS.iter().collect(),
+    };
+    let Ok(iterations) = usize::try_from(args.iterations) else {
+        return Err(format!("Cannot run {} iterations!", args.iterations));
+    };
+    let mut records = Vec::new();
+    for day in days {
+        let number = day.number;
+        for (phase, result) in bench_day(day, iterations, args.sample) {
+            match result {
+                Ok(timings) => {
+                    println!("Day {number:02}, {phase:<6}: {timings}");
+                    records.push(PhaseRecord {
+                        day: number,
+                        iterations: timings.iterations,
+                        max_ns: timings.max.as_nanos(),
+                        median_ns: timings.median.as_nanos(),
+                        min_ns: timings.min.as_nanos(),
+                        phase,
+                    });
+                }
+                Err(err) => eprintln!("Day {number:02}, {phase} failed:\n{err}"),
+            }
+        }
+    }
+    let report = BenchReport {
+        phases: records,
+        sample: args.sample,
+    };
+    let json = serde_json::to_string_pretty(&report)
+        .map_err(|err| format!("Could not serialize the results!\nReason: {err}"))?;
+    let output = args.output.display();
+    fs::write(&args.output, json)
+        .map_err(|err| format!("Could not write the results to {output}!\nReason: {err}"))?;
+    println!("Saved the results to {output}");
+    Ok(())
+}
@@ -0,0 +1,224 @@
+//! Runs the solutions for any day and part of Advent of Code 2025
+//!
+//! Usage: `aoc run <day> <part> [--input PATH | --sample]` or `aoc run --all [--sample]`
+//!
+//! With `aoc bench [<day>] [--iterations N] [--output PATH] [--sample]`, the parse and solve
+//! phases of the solutions are timed instead.
+
+mod bench;
+
+use std::path::{Path, PathBuf};
+
+use aoc_common::bench::{Timings, time_parse, time_part};
+use aoc_common::solution::{InputKind, Part, Solution};
+use bench::BenchArgs;
+use clap::{Args, Parser, Subcommand};
+use day01::Day01;
+use day02::Day02;
+use day03::Day03;
+use day04::Day04;
+use day05::Day05;
+use day06::Day06;
+use day07::Day07;
+use day08::Day08;
+use day09::Day09;
+use day10::Day10;
+use day11::Day11;
+use day12::Day12;
+use log::info;
+
+/// All days that have a solution, in order
+const DAYS: [Day; 12] = [
+    Day::of::<Day01>(),
+    Day::of::<Day02>(),
+    Day::of::<Day03>(),
+    Day::of::<Day04>(),
+    Day::of::<Day05>(),
+    Day::of::<Day06>(),
+    Day::of::<Day07>(),
+    Day::of::<Day08>(),
+    Day::of::<Day09>(),
+    Day::of::<Day10>(),
+    Day::of::<Day11>(),
+    Day::of::<Day12>(),
+];
+
+/// Function that measures how long parsing a puzzle input takes for the given number of iterations
+type ParseBench = fn(&str, InputKind, usize) -> Result<Timings, String>;
+
+/// Function that measures how long solving a part takes for the given number of iterations
+type PartBench = fn(Part, &str, InputKind, usize) -> Result<Timings, String>;
+
+/// Function that solves the given part of a day's puzzle for a puzzle input
+type Solver = fn(Part, &str, InputKind) -> Result<String, String>;
+
+/// A day with a solution that can be run by this CLI
+struct Day {
+    /// Function to benchmark the parser
+    bench_parse: ParseBench,
+    /// Function to benchmark the solvers of the parts
+    bench_part: PartBench,
+    /// Number of the day (1-12)
+    number: u8,
+    /// Parts of the puzzle that have a solution
+    parts: &'static [Part],
+    /// Function to solve the puzzle
+    solve: Solver,
+}
+
+impl Day {
+    /// Registers the solution `S` for the CLI
+    const fn of<S: Solution>() -> Self {
+        Self {
+            bench_parse: time_parse::<S>,
+            bench_part: time_part::<S>,
+            number: S::DAY,
+            parts: S::PARTS,
+            solve: S::solve,
+        }
+    }
+}
+
+/// Command line interface for running Advent of Code 2025 solutions
+#[derive(Debug, Parser)]
+#[command(version, about)]
+struct Cli {
+    /// The command to execute
+    #[command(subcommand)]
+    command: Command,
+}
+
+/// Available commands
+#[derive(Debug, Subcommand)]
+enum Command {
+    /// Measures the parse and solve phases of a single day or of all days
+    Bench(BenchArgs),
+    /// Runs the solution for a single day and part or for all days
+    Run(RunArgs),
+}
+
+/// Arguments for the `run` command
+#[derive(Debug, Args)]
+struct RunArgs {
+    /// Run all solved parts of all days in sequence
+    #[arg(long, conflicts_with_all = ["day", "part", "input"])]
+    all: bool,
+    /// Day to run (1-12)
+    #[arg(
+        required_unless_present = "all",
+        value_parser = clap::value_parser!(u8).range(1..=12)
+    )]
+    day: Option<u8>,
+    /// Input file to use instead of `input.txt` in the folder of the day
+    #[arg(long, conflicts_with = "sample")]
+    input: Option<PathBuf>,
+    /// Part to run (1 or 2)
+    #[arg(required_unless_present = "all")]
+    part: Option<Part>,
+    /// Use the sample input from the exercise description instead of `input.txt`
+    #[arg(long)]
+    sample: bool,
+}
+
+/// Returns the folder that contains the package of the given day
+fn day_folder(day: u8) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("..")
+        .join(format!("day{day:02}"))
+}
+
+/// Returns the default input path for a day and part
+///
+/// If `sample` is set, this is `sample_input_part<N>.txt` if the day has a separate sample for
+/// the given part and `sample_input.txt` otherwise.
+fn default_input_path(day: u8, part: Part, sample: bool) -> PathBuf {
+    let folder = day_folder(day);
+    if !sample {
+        return folder.join("input.txt");
+    }
+    let part_sample = folder.join(format!("sample_input_part{part}.txt"));
+    if part_sample.exists() {
+        part_sample
+    } else {
+        folder.join("sample_input.txt")
+    }
+}
+
+/// Kind of the input file at `path`, which is the sample if `sample` is set or its name says so
+fn input_kind(path: &Path, sample: bool) -> InputKind {
+    if sample {
+        InputKind::Sample
+    } else {
+        InputKind::of(path)
+    }
+}
+
+/// Loads the input file at `path` and runs the solution for `part` of `day` on it
+fn run_day(day: &Day, part: Part, path: &Path, sample: bool) -> Result<String, String> {
+    let number = day.number;
+    info!("Running day {number}, part {part} on {}", path.display());
+    let contents = aoc_common::read_input(path)?;
+    (day.solve)(part, &contents, input_kind(path, sample))
+}
+
+/// Finds the day with the given number
+fn find_day(number: u8) -> Result<&'static Day, String> {
+    DAYS.iter()
+        .find(|day| day.number == number)
+        .ok_or_else(|| format!("There is no solution for day {number} yet!"))
+}
+
+/// Runs all solved parts of all days, reporting errors without aborting
+#[expect(
+    clippy::print_stdout,
+    clippy::print_stderr,
+    reason = "This is a CLI function."
+)]
+fn run_all(sample: bool) {
+    for day in &DAYS {
+        let number = day.number;
+        for part in day.parts {
+            match run_day(
+                day,
+                *part,
+                &default_input_path(number, *part, sample),
+                sample,
+            ) {
+                Ok(answer) => println!("Day {number:02}, part {part}: {answer}"),
+                Err(err) => eprintln!("Day {number:02}, part {part} failed:\n{err}"),
+            }
+        }
+    }
+}
+
+#[expect(clippy::print_stdout, reason = "This is a CLI function.")]
+fn main() {
+    aoc_common::init_logger();
+    let cli = Cli::parse();
+    match cli.command {
+        Command::Bench(args) => {
+            if let Err(err) = bench::run_bench(&args) {
+                aoc_common::exit_with_error(&err);
+            }
+        }
+        Command::Run(args) => {
+            if args.all {
+                run_all(args.sample);
+                return;
+            }
+            let (Some(number), Some(part)) = (args.day, args.part) else {
+                aoc_common::exit_with_error("Either pass a day and a part or use --all.");
+            };
+            let result = find_day(number).and_then(|day| {
+                let path = args
+                    .input
+                    .unwrap_or_else(|| default_input_path(number, part, args.sample));
+                run_day(day, part, &path, args.sample)
+            });
+            match result {
+                Ok(answer) => println!("{answer}"),
+                Err(err) => aoc_common::exit_with_error(&err),
+            }
+        }
+    }
+}
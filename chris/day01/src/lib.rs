@@ -0,0 +1,156 @@
+//! Solves day 1 of Advent of Code 2025
+use core::num::Saturating;
+use core::num::Wrapping;
+use core::ops::Div as _;
+
+use aoc_common::input::{ParseError, parse_token};
+use aoc_common::solution::Solution;
+
+/// Direction in which the dial is turned
+#[derive(Debug, Clone, Copy)]
+pub enum Direction {
+    /// Turn to the left (towards lower numbers)
+    Left,
+    /// Turn to the right (towards higher numbers)
+    Right,
+}
+
+/// List of turn instructions consisting of a direction and the number of ticks
+pub type Instructions = Vec<(Direction, u16)>;
+
+/// Solution for day 1
+#[derive(Debug)]
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+
+    type Input = Instructions;
+
+    fn parse_input(content: &str) -> Result<Self::Input, ParseError> {
+        parse_input(content)
+    }
+
+    fn part1(input: &Self::Input) -> Result<String, String> {
+        Ok(count_zero_rests(input).to_string())
+    }
+
+    fn part2(input: &Self::Input) -> Result<String, String> {
+        Ok(count_zero_crossings(input).to_string())
+    }
+}
+
+/// Parses input for day1 puzzles into a vector containing the direction
+/// (`'L'` or `'R'`) and the number of ticks.
+///
+/// # Errors
+///
+/// Returns an error if a line does not start with `'L'` or `'R'` followed by a number.
+///
+/// # Examples
+///
+/// ```
+/// use day01::{Direction, parse_input};
+///
+/// let instructions = parse_input("L68\nR48")?;
+/// assert!(matches!(
+///     instructions.as_slice(),
+///     [(Direction::Left, 68), (Direction::Right, 48)]
+/// ));
+/// # Ok::<(), aoc_common::input::ParseError>(())
+/// ```
+pub fn parse_input(input: &str) -> Result<Instructions, ParseError> {
+    let mut vector: Instructions = Vec::new();
+    for (idx, line) in input.lines().enumerate() {
+        let instruction = line.trim();
+        // ignore empty lines
+        let Some(dir_char) = instruction.chars().next() else {
+            continue;
+        };
+        let Some((dir_str, ticks_str)) = instruction.split_at_checked(dir_char.len_utf8()) else {
+            continue;
+        };
+        let direction = match dir_char {
+            'L' => Direction::Left,
+            'R' => Direction::Right,
+            _ => {
+                return Err(ParseError::at_token(
+                    idx,
+                    line,
+                    dir_str,
+                    "a direction ('L' or 'R')",
+                ));
+            }
+        };
+        let number = parse_token::<u16>(idx, line, ticks_str, "a number of ticks")?;
+        vector.push((direction, number));
+    }
+    Ok(vector)
+}
+
+/// Counts zero crossings for part two of the puzzle.
+/// More specifically: This counts how often a tick reaches zero when the dial
+/// is turned, also including multiple 360° turns.
+///
+/// # Examples
+///
+/// ```
+/// use day01::{Direction, count_zero_crossings};
+///
+/// // reaches zero once at the end of the first turn and once during the full turn
+/// assert_eq!(count_zero_crossings(&[(Direction::Left, 50), (Direction::Right, 100)]), 2);
+/// ```
+#[must_use]
+pub fn count_zero_crossings(instructions: &[(Direction, u16)]) -> u16 {
+    let mut dial: Wrapping<i16> = Wrapping(50);
+    let mut zero_crossings: Saturating<u16> = Saturating(0);
+    for &(dir, ticks) in instructions {
+        let delta = match dir {
+            Direction::Right => Wrapping(ticks.cast_signed()),
+            Direction::Left => -Wrapping(ticks.cast_signed()),
+        };
+        // case 1: crossings due to a full turn
+        zero_crossings += delta.0.unsigned_abs().div(100);
+        // case 2: crossings due to a partial turn
+        // Partial turns starting at 0 can never lead to a crossing
+        // Otherwise, we can detect a crossing if they end up exactly
+        // at zero or outside the [0, 99] range
+        let remaining = Wrapping(delta.0.wrapping_rem(100)); // remaining partial turn
+        if dial != Wrapping(0)
+            && (dial + remaining <= Wrapping(0) || dial + remaining >= Wrapping(100))
+        {
+            zero_crossings += 1;
+        }
+        dial = Wrapping((dial + remaining).0.rem_euclid(100));
+        // println!("Dial: {dial}, Zero crossings: {zero_crossings}");
+    }
+    zero_crossings.0
+}
+
+/// Counts how often the dial ends up at zero after a turn instruction.
+///
+/// # Examples
+///
+/// ```
+/// use day01::{Direction, count_zero_rests};
+///
+/// assert_eq!(count_zero_rests(&[(Direction::Left, 50), (Direction::Right, 100)]), 2);
+/// assert_eq!(count_zero_rests(&[(Direction::Left, 49), (Direction::Right, 100)]), 0);
+/// ```
+#[must_use]
+pub fn count_zero_rests(instructions: &[(Direction, u16)]) -> i16 {
+    let mut dial: Wrapping<i16> = Wrapping(50);
+    let mut zeros: Saturating<i16> = Saturating(0);
+    for &(dir, ticks) in instructions {
+        dial += match dir {
+            Direction::Right => Wrapping(ticks.cast_signed()),
+            Direction::Left => -Wrapping(ticks.cast_signed()),
+        };
+        dial = Wrapping(dial.0.rem_euclid(100));
+        if dial == Wrapping(0) {
+            zeros += 1;
+        }
+        // println!("Dial: {dial}");
+    }
+    zeros.0
+}
@@ -0,0 +1,138 @@
+//! Solves day 2 of Advent of Code 2025
+
+use core::num::Saturating;
+
+use aoc_common::input::{ParseError, parse_token};
+use aoc_common::solution::Solution;
+use log::debug;
+
+/// Ranges of product IDs (start and end are inclusive)
+pub type IdRanges = Vec<(u64, u64)>;
+
+/// Solution for day 2
+#[derive(Debug)]
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+
+    type Input = IdRanges;
+
+    fn parse_input(content: &str) -> Result<Self::Input, ParseError> {
+        parse_input(content)
+    }
+
+    fn part1(input: &Self::Input) -> Result<String, String> {
+        Ok(sum_invalid_ids_half_length(input).to_string())
+    }
+
+    fn part2(input: &Self::Input) -> Result<String, String> {
+        Ok(sum_invalid_ids_all_lengths(input).to_string())
+    }
+}
+
+/// Parses puzzle input for day 2
+///
+/// # Errors
+///
+/// Returns an error if a range is not of the form `start-end`.
+pub fn parse_input(text: &str) -> Result<IdRanges, ParseError> {
+    let mut result: IdRanges = Vec::new();
+    for (idx, line) in text.lines().enumerate() {
+        for range in line.trim().split(',').filter(|range| !range.is_empty()) {
+            debug!("Range: {range}");
+            let Some((start_str, end_str)) = range.split_once('-') else {
+                return Err(ParseError::at_token(
+                    idx,
+                    line,
+                    range,
+                    "a range of the form `start-end`",
+                ));
+            };
+            let start = parse_token::<u64>(idx, line, start_str, "the first ID of the range")?;
+            debug!("Start: {start}");
+            let end = parse_token::<u64>(idx, line, end_str, "the last ID of the range")?;
+            result.push((start, end));
+            debug!("End: {end}");
+        }
+    }
+    Ok(result)
+}
+
+/// Finds all divisors of `num` (unordered, may contain duplicates for square numbers)
+fn divisors(num: usize) -> Vec<usize> {
+    (1..=num.isqrt())
+        .filter(|div| num.checked_rem(*div) == Some(0))
+        .flat_map(|div| [div, num.checked_div(div).unwrap_or(0)])
+        .collect::<Vec<usize>>()
+}
+
+/// Tests if an ID is invalid
+fn is_invalid(num: u64, all_lengths: bool) -> bool {
+    let chars = num.to_string().chars().collect::<Vec<char>>();
+    let pattern_lengths = if all_lengths {
+        divisors(chars.len())
+    } else if chars.len().is_multiple_of(2) {
+        vec![chars.len().div_euclid(2)]
+    } else {
+        Vec::new()
+    };
+    debug!("{num}: pattern lengths {pattern_lengths:?}");
+    for length in pattern_lengths {
+        if length == chars.len() {
+            continue;
+        }
+        let mut chunks = chars.chunks(length);
+        let first = chunks.next();
+        if chunks.all(|chunk| Some(chunk) == first) {
+            debug!("Invalid: {num}, Length: {length}");
+            return true;
+        }
+    }
+    false
+}
+
+/// Find the sum of all invalid IDs
+fn sum_invalid_ids(data: &[(u64, u64)], all_lengths: bool) -> u64 {
+    let mut invalid: Saturating<u64> = Saturating(0);
+    for &(start, end) in data {
+        debug!("Start: {start}, End: {end}");
+        for id in start..=end {
+            if is_invalid(id, all_lengths) {
+                invalid += id;
+            }
+        }
+    }
+    invalid.0
+}
+
+/// Find the sum of all invalid IDs only considering IDs which consist of
+/// two repeated patterns.
+///
+/// # Examples
+///
+/// ```
+/// use day02::sum_invalid_ids_half_length;
+///
+/// // 11, 22 and 99
+/// assert_eq!(sum_invalid_ids_half_length(&[(11, 22), (95, 115)]), 132);
+/// ```
+#[must_use]
+pub fn sum_invalid_ids_half_length(data: &[(u64, u64)]) -> u64 {
+    sum_invalid_ids(data, false)
+}
+
+/// Find sum of all invalid IDs considering repeating patterns of any length.
+///
+/// # Examples
+///
+/// ```
+/// use day02::sum_invalid_ids_all_lengths;
+///
+/// // 11, 22, 99 and 111
+/// assert_eq!(sum_invalid_ids_all_lengths(&[(11, 22), (95, 115)]), 243);
+/// ```
+#[must_use]
+pub fn sum_invalid_ids_all_lengths(data: &[(u64, u64)]) -> u64 {
+    sum_invalid_ids(data, true)
+}
@@ -0,0 +1,103 @@
+//! Solution for day 3 of Advent of Code 2025
+use aoc_common::input::ParseError;
+use aoc_common::solution::Solution;
+use log::debug;
+
+/// Solution for day 3
+#[derive(Debug)]
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+
+    type Input = Vec<Vec<u8>>;
+
+    fn parse_input(content: &str) -> Result<Self::Input, ParseError> {
+        parse_input(content)
+    }
+
+    fn part1(input: &Self::Input) -> Result<String, String> {
+        Ok(sum_max_joltages(input, 2).to_string())
+    }
+
+    fn part2(input: &Self::Input) -> Result<String, String> {
+        Ok(sum_max_joltages(input, 12).to_string())
+    }
+}
+
+/// Parses puzzle input for day 3
+///
+/// # Errors
+///
+/// Returns an error if a bank contains a character that is not a digit.
+pub fn parse_input(text: &str) -> Result<Vec<Vec<u8>>, ParseError> {
+    let mut result: Vec<Vec<u8>> = Vec::new();
+    for (idx, bank) in text.lines().enumerate() {
+        debug!("Bank: {bank}");
+        let batteries = bank
+            .trim_end()
+            .char_indices()
+            .map(|(col, chr)| {
+                chr.to_digit(10)
+                    .and_then(|digit| u8::try_from(digit).ok())
+                    .ok_or_else(|| {
+                        let columns = col..col.saturating_add(chr.len_utf8());
+                        ParseError::at(idx, bank, columns, "a battery joltage (0-9)")
+                    })
+            })
+            .collect::<Result<Vec<u8>, ParseError>>()?;
+        result.push(batteries);
+    }
+    Ok(result)
+}
+
+/// Compute max joltage
+///
+/// # Examples
+///
+/// ```
+/// use day03::max_joltage;
+///
+/// assert_eq!(max_joltage(&[1, 2, 3, 4], 2), 34);
+/// assert_eq!(max_joltage(&[8, 2, 3, 9], 2), 89);
+/// ```
+#[must_use]
+pub fn max_joltage(bank: &[u8], num_active: u8) -> u64 {
+    debug!("Calculating max joltage for {bank:?}");
+    let mut offset = 0;
+    let mut active: Vec<u8> = Vec::new();
+    for remaining_batteries in (0..num_active).rev() {
+        let search_range =
+            bank.get(offset..bank.len().saturating_sub(usize::from(remaining_batteries)));
+        debug!("Search range: {search_range:?}");
+        let first_highest = search_range.and_then(|rng| {
+            rng.iter()
+                .enumerate()
+                .rev()
+                .max_by_key(|&(_, digit)| *digit)
+        });
+        let Some((highest_idx, highest_val)) = first_highest else {
+            return 0;
+        };
+        debug!("Select battery to activate: Index {highest_idx}, value {highest_val}");
+        active.push(*highest_val);
+        offset = offset.saturating_add(highest_idx).saturating_add(1);
+    }
+    debug!("Active battery values: {active:?}");
+    let Ok(max_joltage) = active
+        .iter()
+        .map(u8::to_string)
+        .collect::<String>()
+        .parse::<u64>()
+    else {
+        return 0;
+    };
+    debug!("Found max joltage {max_joltage} for bank {bank:?}.");
+    max_joltage
+}
+
+/// Compute max joltage sum
+#[must_use]
+pub fn sum_max_joltages(input: &[Vec<u8>], num_active: u8) -> u64 {
+    input.iter().map(|x| max_joltage(x, num_active)).sum()
+}
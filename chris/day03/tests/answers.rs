@@ -0,0 +1,12 @@
+//! Regression tests for day 3 against the answers in `expected_answers.txt`
+
+use std::path::Path;
+
+use aoc_common::regression::check_expected_answers;
+use day03::Day03;
+
+/// Checks that the solution still produces all known answers
+#[test]
+fn expected_answers() -> Result<(), String> {
+    check_expected_answers::<Day03>(Path::new(env!("CARGO_MANIFEST_DIR")))
+}
@@ -0,0 +1,115 @@
+//! Solve day 4 of Advent of Code 2025
+use core::num::Saturating;
+
+use aoc_common::grid::Grid;
+use aoc_common::input::ParseError;
+use aoc_common::solution::Solution;
+use log::debug;
+
+/// Stack of paper rolls on the map
+const STACK: char = '@';
+
+/// Empty space on the map
+const EMPTY: char = '.';
+
+/// Solution for day 4
+#[derive(Debug)]
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+
+    type Input = Grid<char>;
+
+    fn parse_input(content: &str) -> Result<Self::Input, ParseError> {
+        parse_input(content)
+    }
+
+    fn part1(input: &Self::Input) -> Result<String, String> {
+        Ok(count_movable(input).to_string())
+    }
+
+    fn part2(input: &Self::Input) -> Result<String, String> {
+        Ok(count_and_delete_movable(input).to_string())
+    }
+}
+
+/// Parses puzzle input for day 4
+///
+/// # Errors
+///
+/// Returns an error if the input is empty, if the rows differ in length
+/// or if a row contains something else than empty space ('.') and stacks ('@').
+pub fn parse_input(text: &str) -> Result<Grid<char>, ParseError> {
+    Grid::parse(text, "empty space ('.') or a stack ('@')", |chr| {
+        (chr == EMPTY || chr == STACK).then_some(chr)
+    })
+}
+
+/// Solves part 1 of the puzzle
+/// This finds movable stacks ('@'), that is stacks that have less than 4 neighbors.
+///
+/// # Examples
+///
+/// ```
+/// use day04::{count_movable, parse_input};
+///
+/// // only the corners have less than 4 neighbors
+/// let mat = parse_input("@@@\n@@@\n@@@")?;
+/// assert_eq!(count_movable(&mat), 4);
+/// # Ok::<(), aoc_common::input::ParseError>(())
+/// ```
+#[must_use]
+pub fn count_movable(mat: &Grid<char>) -> usize {
+    mat.iter()
+        .filter(|&(_, &chr)| chr == STACK)
+        .filter(|&((row, col), _)| {
+            let neighbors = neighbors_at(mat, row, col);
+            if neighbors < 4 {
+                debug!(
+                    "Found movable position at row {row}, col {col} with {neighbors} neighbors."
+                );
+            }
+            neighbors < 4
+        })
+        .count()
+}
+
+/// Solves part 2
+/// Count movable stacks and remove them, repeat until no more can be removed.
+#[must_use]
+pub fn count_and_delete_movable(mat: &Grid<char>) -> usize {
+    // note: Duplication of delete_movable call is needed because the matrix we begin with
+    // is borrowed but the matrices we then generate are owned by us
+    let (mut cur_mat, mut deleted) = delete_movable(mat);
+    debug!("Deleted {deleted} stacks.");
+    let mut movable = Saturating(deleted);
+    while deleted > 0 {
+        (cur_mat, deleted) = delete_movable(&cur_mat);
+        debug!("Deleted {deleted} stacks.");
+        movable += deleted;
+    }
+    movable.0
+}
+
+/// Deletes movable stacks
+/// Returns updated map and number of stacks removed
+fn delete_movable(mat: &Grid<char>) -> (Grid<char>, usize) {
+    let mut counter: Saturating<usize> = Saturating(0);
+    let new_mat = mat.map_indexed(|(row, col), &chr| {
+        if chr == STACK && neighbors_at(mat, row, col) < 4 {
+            counter += 1;
+            EMPTY
+        } else {
+            chr
+        }
+    });
+    (new_mat, counter.0)
+}
+
+/// Count the neighboring stacks at a position
+fn neighbors_at(mat: &Grid<char>, row: usize, col: usize) -> usize {
+    mat.neighbors8(row, col)
+        .filter(|&(_, &chr)| chr == STACK)
+        .count()
+}
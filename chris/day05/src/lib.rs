@@ -0,0 +1,115 @@
+//! Solves day 5 of Advent of Code 2025
+
+use core::num::Saturating;
+
+use aoc_common::input::{ParseError, parse_token};
+use aoc_common::solution::Solution;
+use log::info;
+
+/// Ranges of fresh ingredients
+pub type FreshRanges = Vec<(u64, u64)>;
+
+/// Solution for day 5
+#[derive(Debug)]
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+
+    type Input = (FreshRanges, Vec<u64>);
+
+    fn parse_input(content: &str) -> Result<Self::Input, ParseError> {
+        parse_input(content)
+    }
+
+    fn part1(input: &Self::Input) -> Result<String, String> {
+        Ok(count_fresh_ingredients(&input.0, &input.1).to_string())
+    }
+
+    fn part2(input: &Self::Input) -> Result<String, String> {
+        Ok(count_all_fresh_ids(&input.0).to_string())
+    }
+}
+
+/// Parses input for day 5
+///
+/// # Errors
+///
+/// Returns an error message if a line is neither a range nor an ingredient ID.
+pub fn parse_input(content: &str) -> Result<(FreshRanges, Vec<u64>), ParseError> {
+    let mut fresh_ranges: Vec<(u64, u64)> = Vec::new();
+    let mut ingredients: Vec<u64> = Vec::new();
+    for (idx, line) in content.lines().enumerate() {
+        if let Some((start_str, end_str)) = line.split_once('-') {
+            // parse new fresh range
+            let start = parse_token::<u64>(idx, line, start_str, "the first ID of the range")?;
+            let end = parse_token::<u64>(idx, line, end_str, "the last ID of the range")?;
+            fresh_ranges.push((start, end));
+        } else if !line.is_empty() {
+            // parse new ingredient
+            ingredients.push(parse_token::<u64>(idx, line, line, "an ingredient ID")?);
+        } else {
+            // Skip empty line
+        }
+    }
+    Ok((fresh_ranges, ingredients))
+}
+
+/// Counts the number of ingredient IDs that are fresh
+///
+/// # Examples
+///
+/// ```
+/// use day05::count_fresh_ingredients;
+///
+/// assert_eq!(count_fresh_ingredients(&vec![(3, 5), (10, 14)], &[1, 5, 8, 11]), 2);
+/// ```
+#[must_use]
+pub fn count_fresh_ingredients(fresh_ranges: &FreshRanges, ingredients: &[u64]) -> usize {
+    ingredients
+        .iter()
+        .filter(|ingredient| {
+            fresh_ranges
+                .iter()
+                .any(|&(start, end)| ingredient >= &&start && ingredient <= &&end)
+        })
+        .count()
+}
+
+/// Counts the number of ingredient IDs in any fresh range
+///
+/// # Examples
+///
+/// ```
+/// use day05::count_all_fresh_ids;
+///
+/// // 3-5 and the overlapping ranges 10-18
+/// assert_eq!(count_all_fresh_ids(&vec![(3, 5), (10, 14), (12, 18)]), 12);
+/// ```
+#[must_use]
+pub fn count_all_fresh_ids(fresh_ranges: &FreshRanges) -> u64 {
+    let mut sorted_ranges = fresh_ranges.clone();
+    sorted_ranges.sort_by_key(|&(start, _)| start);
+    // add sentinel
+    sorted_ranges.push((u64::MAX, u64::MAX - 1));
+    let mut current_start = 0;
+    let mut current_end = 0;
+    let mut fresh_ids: Saturating<u64> = Saturating(0);
+    for (start, end) in sorted_ranges {
+        if start <= current_end {
+            // start is still inside the current range => only update end
+            current_end = end.max(current_end);
+        } else {
+            if (current_start, current_end) != (0, 0) {
+                let increment = Saturating(current_end) - Saturating(current_start) + Saturating(1);
+                fresh_ids += increment;
+                info!(
+                    "Found {increment} new fresh IDs in merged range {current_start}-{current_end}."
+                );
+            }
+            current_start = start;
+            current_end = end;
+        }
+    }
+    fresh_ids.0
+}
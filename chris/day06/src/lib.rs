@@ -0,0 +1,211 @@
+//! Solves day 6 of Advent of Code 2025
+
+use core::iter::zip;
+
+use aoc_common::grid::Grid;
+use aoc_common::input::{ParseError, parse_token};
+use aoc_common::solution::Solution;
+
+/// An operator for combining multiple numbers
+#[derive(Debug, Clone, Copy)]
+pub enum Operator {
+    /// Addition
+    Add,
+    /// Multiplication
+    Mul,
+}
+
+/// A math problem consisting of a list of numbers and an operator (+ or *)
+pub type MathProblem = (Vec<u64>, Operator);
+
+/// Type of an input line
+enum LineType {
+    /// Represents a line containing (unsigned) numbers
+    Number,
+    /// Represents a line containing the operators + and *
+    Operator,
+}
+
+/// Math problems of a worksheet, read in both ways described by the puzzle
+#[derive(Debug)]
+pub struct Worksheet {
+    /// Problems with numbers read column by column (part 2)
+    by_column: Vec<MathProblem>,
+    /// Problems with numbers read row by row (part 1)
+    by_row: Vec<MathProblem>,
+}
+
+/// Solution for day 6
+#[derive(Debug)]
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+
+    type Input = Worksheet;
+
+    fn parse_input(content: &str) -> Result<Self::Input, ParseError> {
+        Ok(Worksheet {
+            by_column: parse_input2(content)?,
+            by_row: parse_input(content)?,
+        })
+    }
+
+    fn part1(input: &Self::Input) -> Result<String, String> {
+        Ok(solve_math_problems(&input.by_row).to_string())
+    }
+
+    fn part2(input: &Self::Input) -> Result<String, String> {
+        Ok(solve_math_problems(&input.by_column).to_string())
+    }
+}
+
+/// Finds out the line type of a line in the input
+fn line_type(line: &str) -> Option<LineType> {
+    match line.split_ascii_whitespace().next()?.chars().next()? {
+        '+' | '*' => Some(LineType::Operator),
+        _ => Some(LineType::Number),
+    }
+}
+
+/// Parses an operator symbol
+const fn parse_operator(symbol: char) -> Option<Operator> {
+    match symbol {
+        '+' => Some(Operator::Add),
+        '*' => Some(Operator::Mul),
+        _ => None,
+    }
+}
+
+/// Creates an error pointing to the character in column `col` (0-based) of the line `row`
+fn error_at_char(lines: &[&str], row: usize, col: usize, expected: &str) -> ParseError {
+    let line = lines.get(row).copied().unwrap_or_default();
+    let columns = line
+        .char_indices()
+        .nth(col)
+        .map_or(line.len()..line.len(), |(start, chr)| {
+            start..start.saturating_add(chr.len_utf8())
+        });
+    ParseError::at(row, line, columns, expected)
+}
+
+/// Parses input
+/// This assumes that each line in the input except for the last one contains the
+/// same amount of numbers while the last one contains the same number of '*' or '+' symbols.
+///
+/// # Errors
+///
+/// Returns an error if a number or an operator cannot be parsed.
+pub fn parse_input(content: &str) -> Result<Vec<MathProblem>, ParseError> {
+    let mut result: Vec<MathProblem> = Vec::new();
+    for (idx, line) in content.lines().enumerate() {
+        let elements = line.split_ascii_whitespace().collect::<Vec<&str>>();
+        let Some(line_type) = line_type(line) else {
+            continue;
+        };
+        match line_type {
+            LineType::Operator => {
+                // set the operators
+                for (op_as_str, math_problem) in zip(elements, result.iter_mut()) {
+                    let mut symbols = op_as_str.chars();
+                    let (Some(symbol), None) = (symbols.next(), symbols.next()) else {
+                        return Err(ParseError::at_token(idx, line, op_as_str, "'+' or '*'"));
+                    };
+                    math_problem.1 = parse_operator(symbol)
+                        .ok_or_else(|| ParseError::at_token(idx, line, op_as_str, "'+' or '*'"))?;
+                }
+            }
+            LineType::Number => {
+                // add a new line of numbers
+                let numbers = elements
+                    .iter()
+                    .map(|element| parse_token::<u64>(idx, line, element, "a number"))
+                    .collect::<Result<Vec<u64>, ParseError>>()?;
+                // extend result array if it is empty
+                if result.is_empty() {
+                    for num in numbers {
+                        result.push((vec![num], Operator::Add));
+                    }
+                } else {
+                    for (num, math_problem) in zip(numbers, result.iter_mut()) {
+                        math_problem.0.push(num);
+                    }
+                }
+            }
+        }
+    }
+    Ok(result)
+}
+
+/// Parses input for part 2
+///
+/// # Errors
+///
+/// Returns an error if a number or an operator cannot be parsed.
+pub fn parse_input2(content: &str) -> Result<Vec<MathProblem>, ParseError> {
+    let lines = content.lines().collect::<Vec<&str>>();
+    let grid = Grid::from_rows_padded(
+        lines
+            .iter()
+            .map(|line| line.chars().collect::<Vec<char>>())
+            .collect(),
+        &' ',
+    );
+    let operator_row = grid.rows().saturating_sub(1_usize);
+    let cols = grid.columns();
+    let mut result = Vec::new();
+    let mut current_operator = Operator::Add;
+    let mut current_numbers: Vec<u64> = Vec::new();
+    for col in 0..=cols {
+        let digit_at = |digit_row: usize| grid.get(digit_row, col).copied().unwrap_or(' ');
+        let digits = (0..operator_row).map(digit_at).collect::<String>();
+        let operator = digit_at(operator_row);
+        if operator != ' ' {
+            current_operator = parse_operator(operator)
+                .ok_or_else(|| error_at_char(&lines, operator_row, col, "'+' or '*'"))?;
+        }
+        // Check if we have an empty column
+        if digits.trim().is_empty() && operator == ' ' {
+            result.push((current_numbers.clone(), current_operator));
+            current_numbers = Vec::new();
+        } else {
+            let Ok(number) = digits.trim().parse::<u64>() else {
+                // point to the first character that breaks the number in this column
+                let row = (0..operator_row)
+                    .find(|&digit_row| {
+                        !digit_at(digit_row).is_ascii_digit() && digit_at(digit_row) != ' '
+                    })
+                    .unwrap_or(0);
+                return Err(error_at_char(
+                    &lines,
+                    row,
+                    col,
+                    "a digit of a number written top to bottom",
+                ));
+            };
+            current_numbers.push(number);
+        }
+    }
+    Ok(result)
+}
+
+/// Solves all math problems in a list
+///
+/// # Examples
+///
+/// ```
+/// use day06::{Operator, solve_math_problems};
+///
+/// let problems = [(vec![2, 3], Operator::Add), (vec![2, 3, 4], Operator::Mul)];
+/// assert_eq!(solve_math_problems(&problems), 29);
+/// ```
+#[must_use]
+pub fn solve_math_problems(problems: &[MathProblem]) -> u64 {
+    problems
+        .iter()
+        .map(|problem| match problem.1 {
+            Operator::Add => problem.0.iter().sum::<u64>(),
+            Operator::Mul => problem.0.iter().product(),
+        })
+        .sum()
+}
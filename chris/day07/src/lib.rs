@@ -0,0 +1,112 @@
+//! Solves day 7 of Advent of code 2025
+
+use std::collections::HashSet;
+
+use aoc_common::grid::{Grid, Position};
+use aoc_common::input::ParseError;
+use aoc_common::solution::{Part, Solution};
+
+/// A single cell of the tachyon manifold
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Tile {
+    /// Empty space ('.')
+    Empty,
+    /// A splitter ('^')
+    Splitter,
+    /// The starting position of the beam ('S')
+    Start,
+}
+
+/// Represents a tachyon manifold
+#[derive(Debug)]
+pub struct TachyonManifold {
+    /// The cells of the manifold, starting from the top
+    grid: Grid<Tile>,
+    /// Starting position
+    start: Position,
+}
+
+/// Solution for day 7
+#[derive(Debug)]
+pub struct Day07;
+
+impl Solution for Day07 {
+    #[expect(
+        clippy::decimal_literal_representation,
+        reason = "Day numbers are more readable in decimal notation."
+    )]
+    const DAY: u8 = 7;
+    const PARTS: &'static [Part] = &[Part::One];
+
+    type Input = TachyonManifold;
+
+    fn parse_input(content: &str) -> Result<Self::Input, ParseError> {
+        parse_input(content)
+    }
+
+    fn part1(input: &Self::Input) -> Result<String, String> {
+        Ok(count_splits(input).to_string())
+    }
+}
+
+/// Parses input string containing of a single line marking a starting position ('S')
+/// and multiple lines that can have splitters ('^').
+///
+/// Empty space is marked with a '.'.
+///
+/// # Errors
+///
+/// Returns an error if a line contains any other character, if the lines differ in length
+/// or if there is no starting position.
+pub fn parse_input(content: &str) -> Result<TachyonManifold, ParseError> {
+    let grid = Grid::parse(
+        content,
+        "empty space ('.'), the start ('S') or a splitter ('^')",
+        |chr| match chr {
+            '.' => Some(Tile::Empty),
+            '^' => Some(Tile::Splitter),
+            'S' => Some(Tile::Start),
+            _ => None,
+        },
+    )?;
+    let Some((start, _)) = grid.iter().find(|&(_, &tile)| tile == Tile::Start) else {
+        return Err(ParseError::in_input(
+            "a line with the starting position ('S')",
+        ));
+    };
+    Ok(TachyonManifold { grid, start })
+}
+
+/// Counts the number of splits that the beam will encounter
+///
+/// # Examples
+///
+/// ```
+/// use day07::{count_splits, parse_input};
+///
+/// let manifold = parse_input("..S..\n.....\n..^..\n.....\n.^.^.")?;
+/// assert_eq!(count_splits(&manifold), 3);
+/// # Ok::<(), aoc_common::input::ParseError>(())
+/// ```
+#[must_use]
+pub fn count_splits(input: &TachyonManifold) -> usize {
+    let mut beam_columns: HashSet<usize> = HashSet::new();
+    let mut split: usize = 0;
+    let (start_row, start_col) = input.start;
+    beam_columns.insert(start_col);
+    for row in input.grid.iter_rows().skip(start_row.saturating_add(1)) {
+        // compare beam positions with splitters
+        let mut new_beam_columns = HashSet::new();
+        for beam_index in &beam_columns {
+            if row.get(*beam_index) == Some(&Tile::Splitter) {
+                split = split.saturating_add(1);
+                new_beam_columns.insert(beam_index.saturating_sub(1));
+                new_beam_columns.insert(beam_index.saturating_add(1));
+            } else {
+                new_beam_columns.insert(*beam_index);
+            }
+        }
+        beam_columns = new_beam_columns;
+    }
+    split
+}
@@ -0,0 +1,212 @@
+//! Solves day 8 of Advent of Code 2025
+
+use aoc_common::geometry::Point3;
+use aoc_common::input::ParseError;
+use aoc_common::kd_tree::KdTree;
+use aoc_common::metric::{Euclidean, Metric};
+use aoc_common::solution::{InputKind, Solution};
+use aoc_common::union_find::UnionFind;
+use log::{debug, info};
+
+/// Number of connections for part 1 on the real input
+pub const INPUT_CONNECTIONS: usize = 1000;
+
+/// Number of connections for part 1 on the sample input
+pub const SAMPLE_CONNECTIONS: usize = 10;
+
+/// Number of largest circuits whose sizes are multiplied in part 1
+pub const TOP_CIRCUITS: usize = 3;
+
+/// Represents a position of a junction box in 3D Cartesian coordinates
+pub type Position3D = Point3<i64>;
+
+/// Junction boxes of a puzzle input with the number of connections for part 1
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct JunctionBoxes {
+    /// Number of closest pairs to connect in part 1, which is smaller for the sample
+    pub connections: usize,
+    /// Positions of the junction boxes in the order of the input
+    pub positions: Vec<Position3D>,
+}
+
+/// Connection between two junction boxes in a minimum spanning tree
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct TreeEdge {
+    /// Distance between the junction boxes as measured by the metric of the tree
+    pub distance: u64,
+    /// Index of the first junction box in the input
+    pub first: usize,
+    /// Index of the second junction box in the input
+    pub second: usize,
+}
+
+impl TreeEdge {
+    /// Length of the connection under the metric that was used to build the tree
+    #[must_use]
+    pub fn length<M: Metric>(&self, metric: M) -> f64 {
+        metric.length(self.distance)
+    }
+}
+
+/// Solution for day 8
+#[derive(Debug)]
+pub struct Day08;
+
+impl Solution for Day08 {
+    #[expect(
+        clippy::decimal_literal_representation,
+        reason = "Day numbers are more readable in decimal notation."
+    )]
+    const DAY: u8 = 8;
+
+    type Input = JunctionBoxes;
+
+    fn parse_input(content: &str) -> Result<Self::Input, ParseError> {
+        Self::parse_input_of_kind(content, InputKind::Puzzle)
+    }
+
+    fn parse_input_of_kind(content: &str, kind: InputKind) -> Result<Self::Input, ParseError> {
+        Ok(JunctionBoxes {
+            connections: default_connections(kind),
+            positions: parse_input(content)?,
+        })
+    }
+
+    fn part1(input: &Self::Input) -> Result<String, String> {
+        let product = count_connected(&input.positions, input.connections, TOP_CIRCUITS, Euclidean);
+        Ok(product.to_string())
+    }
+
+    fn part2(input: &Self::Input) -> Result<String, String> {
+        let (first, second) = last_connection(&input.positions, Euclidean)
+            .ok_or_else(|| "At least two junction boxes are needed!".to_owned())?;
+        first
+            .x
+            .checked_mul(second.x)
+            .map(|product| product.to_string())
+            .ok_or_else(|| "The product of the X coordinates does not fit into an i64!".to_owned())
+    }
+}
+
+/// Parses input for day 8
+///
+/// # Errors
+///
+/// Returns an error if a line does not contain exactly three numbers.
+pub fn parse_input(content: &str) -> Result<Vec<Position3D>, ParseError> {
+    content
+        .lines()
+        .enumerate()
+        .map(|(idx, line)| Position3D::parse(idx, line))
+        .collect()
+}
+
+/// Number of connections for part 1, which is smaller for the sample than for the real input
+///
+/// # Examples
+///
+/// ```
+/// use std::path::Path;
+///
+/// use aoc_common::solution::InputKind;
+/// use day08::default_connections;
+///
+/// assert_eq!(default_connections(InputKind::of(Path::new("day08/sample_input.txt"))), 10);
+/// assert_eq!(default_connections(InputKind::of(Path::new("day08/input.txt"))), 1000);
+/// ```
+#[must_use]
+pub const fn default_connections(kind: InputKind) -> usize {
+    match kind {
+        InputKind::Puzzle => INPUT_CONNECTIONS,
+        InputKind::Sample => SAMPLE_CONNECTIONS,
+    }
+}
+
+/// Connects the `connections` closest pairs of junction boxes and returns the sizes of the
+/// resulting circuits in descending order
+///
+/// Pairs that are already in the same circuit count as connections, too.
+#[must_use]
+pub fn circuit_sizes<M: Metric>(
+    positions: &[Position3D],
+    connections: usize,
+    metric: M,
+) -> Vec<usize> {
+    let mut circuits = UnionFind::new(positions.len());
+    let index = KdTree::new(positions, metric);
+    for (distance, first, second) in index.pairs().take(connections) {
+        let (pos1, pos2) = (positions.get(first), positions.get(second));
+        if circuits.union(first, second) {
+            info!("Connecting {pos1:?} and {pos2:?} with distance {distance}.");
+        } else {
+            debug!("Skipping connection between {pos1:?} and {pos2:?} in the same circuit.");
+        }
+    }
+    let sizes = circuits.set_sizes();
+    info!("Found {} circuits with sizes {sizes:?}", sizes.len());
+    sizes
+}
+
+/// Solves part 1 of day 8
+///
+/// Connects the `connections` closest pairs of junction boxes and multiplies the sizes of the
+/// `top` largest circuits.
+#[must_use]
+pub fn count_connected<M: Metric>(
+    positions: &[Position3D],
+    connections: usize,
+    top: usize,
+    metric: M,
+) -> usize {
+    circuit_sizes(positions, connections, metric)
+        .iter()
+        .take(top)
+        .product()
+}
+
+/// Connects the closest junction boxes that are not in the same circuit yet until all of them
+/// form a single circuit (Kruskal's algorithm)
+///
+/// Returns the edges of the resulting minimum spanning tree in the order they were added.
+#[must_use]
+pub fn minimum_spanning_tree<M: Metric>(positions: &[Position3D], metric: M) -> Vec<TreeEdge> {
+    let mut circuits = UnionFind::new(positions.len());
+    let mut tree = Vec::new();
+    let index = KdTree::new(positions, metric);
+    for (distance, first, second) in index.pairs() {
+        if circuits.set_count() <= 1 {
+            break;
+        }
+        if circuits.union(first, second) {
+            debug!("Adding connection {first} - {second} with distance {distance}.");
+            tree.push(TreeEdge {
+                distance,
+                first,
+                second,
+            });
+        }
+    }
+    info!(
+        "Connected {} junction boxes with {} connections.",
+        positions.len(),
+        tree.len()
+    );
+    tree
+}
+
+/// Finds the pair of junction boxes whose connection joins all of them into a single circuit
+///
+/// Returns `None` if there are less than two junction boxes.
+#[must_use]
+pub fn last_connection<M: Metric>(
+    positions: &[Position3D],
+    metric: M,
+) -> Option<(&Position3D, &Position3D)> {
+    let last = *minimum_spanning_tree(positions, metric).last()?;
+    let pair = (positions.get(last.first)?, positions.get(last.second)?);
+    info!(
+        "The last connection is between {:?} and {:?}.",
+        pair.0, pair.1
+    );
+    Some(pair)
+}
@@ -1,190 +1,98 @@
-//! Solves day 8 of Advent of Code 2025
+//! Runs the solution for day 8 of Advent of Code 2025
 
-use core::fmt::Write as _;
-use core::num::ParseIntError;
-use std::collections::{self, HashSet};
-use std::{collections::HashMap, env::args, fs, path::Path, process::exit};
+use aoc_common::kd_tree::KdTree;
+use aoc_common::metric::{Metric as _, Routing};
+use aoc_common::solution::run_cli_with;
+use clap::Args;
+use day08::{
+    Day08, JunctionBoxes, Position3D, TOP_CIRCUITS, circuit_sizes, last_connection, parse_input,
+};
 
-use log::debug;
-use log::info;
-
-/// Represents a position in 3D Cartesian coordinates
-#[derive(Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
-struct Position3D {
-    /// Position on x axis
-    x: usize,
-    /// Position on y axis
-    y: usize,
-    /// Position on z axis
-    z: usize,
-}
-
-impl Position3D {
-    /// Calculates euclidean distance between two positions
-    #[expect(
-        clippy::cast_precision_loss,
-        reason = "We have to convert to float to take the square root."
-    )]
-    #[expect(
-        clippy::as_conversions,
-        reason = "As conversion from usize to u64 and from i64 to f64 is safe."
+/// Arguments for running the puzzle with other numbers or metrics than those of the puzzle
+/// or for finding the junction boxes close to a coordinate
+#[derive(Debug, Args)]
+struct CircuitArgs {
+    /// Show the junction boxes closest to the coordinate `X,Y,Z` instead of solving the puzzle
+    #[arg(
+        long,
+        value_name = "X,Y,Z",
+        allow_hyphen_values = true,
+        conflicts_with_all = ["connections", "top"]
     )]
-    fn dist(&self, other: &Self) -> f64 {
-        let square_sum = (self.x.abs_diff(other.x))
-            .pow(2)
-            .saturating_add((self.y.abs_diff(other.y)).pow(2))
-            .saturating_add((self.z.abs_diff(other.z)).pow(2));
-        (square_sum as u64 as f64).sqrt()
-    }
+    closest: Option<String>,
+    /// Number of closest pairs to connect [default: 10 for the sample, 1000 otherwise]
+    #[arg(long)]
+    connections: Option<usize>,
+    /// Measure distances with this metric and show the circuits and the last connection
+    #[arg(long, value_enum)]
+    metric: Option<Routing>,
+    /// Number of junction boxes to show with `--closest`
+    #[arg(long, default_value_t = 1, requires = "closest")]
+    neighbors: usize,
+    /// Show all junction boxes within this distance under the metric with `--closest`
+    #[arg(long, requires = "closest", conflicts_with = "neighbors")]
+    radius: Option<u64>,
+    /// Number of largest circuits whose sizes are multiplied [default: 3]
+    #[arg(long)]
+    top: Option<usize>,
 }
 
-/// Represents a matrix of distances which can be queried by `Position3D` objects
-type DistanceMatrix<'link> = HashMap<(&'link Position3D, &'link Position3D), f64>;
-
-/// Parses input for day 8
-fn parse_input(content: &str) -> Result<Vec<Position3D>, String> {
-    content
-        .lines()
-        .map(|line| {
-            let Ok(numbers) = line
-                .split(',')
-                .map(str::parse::<usize>)
-                .collect::<Result<Vec<usize>, ParseIntError>>()
-            else {
-                return Err(format!("Could not parse numbers in line {line}"));
-            };
-            let &[x, y, z] = numbers.as_slice() else {
-                return Err(format!("Wrong number of coordinates: {numbers:?}"));
-            };
-            Ok(Position3D { x, y, z })
-        })
-        .collect()
-}
-
-/// Creates a new distance matrix containing the distances between all given positions
-#[expect(
-    clippy::indexing_slicing,
-    reason = "If we are out of index here, it's a programming error and we want to panic."
-)]
-fn distance_matrix(positions: &[Position3D]) -> DistanceMatrix<'_> {
-    let mut map = HashMap::new();
-    for pos1_idx in 0..positions.len() {
-        for pos2_idx in pos1_idx..positions.len() {
-            let pos1 = &positions[pos1_idx];
-            let pos2 = &positions[pos2_idx];
-            let dist = pos1.dist(pos2);
-            map.insert((pos1, pos2), dist);
-            map.insert((pos2, pos1), dist);
-            debug!("Distance between {pos1:?} and {pos2:?} is {dist}.");
-        }
+/// Runs the puzzle with the given numbers or metric, if any of them is given
+fn run_circuits(args: &CircuitArgs, boxes: &JunctionBoxes) -> Result<Option<String>, String> {
+    let positions = &boxes.positions;
+    if let Some(coordinate) = args.closest.as_deref() {
+        return find_closest(args, coordinate, positions).map(Some);
     }
-    map
-}
-
-/// Gets the `num` pairs in `matrix` with the shortest distances to each other
-fn shortest_distances<'link>(
-    matrix: &DistanceMatrix<'link>,
-) -> Vec<(&'link Position3D, &'link Position3D)> {
-    let mut positions: Vec<&(&Position3D, &Position3D)> =
-        matrix.keys().filter(|&&(pos1, pos2)| pos1 < pos2).collect();
-    positions.sort_by(|pair1, pair2| {
-        let dist1 = matrix.get(pair1).unwrap_or(&f64::INFINITY);
-        let dist2 = matrix.get(pair2).unwrap_or(&f64::INFINITY);
-        // debug!("Distance between {pair1:?} is {dist1} and distance between {pair2:?} is {dist2}.");
-        dist1.total_cmp(dist2)
-    });
-    positions.iter().map(|x| **x).collect()
+    if args.connections.is_none() && args.top.is_none() && args.metric.is_none() {
+        return Ok(None);
+    }
+    let metric = args.metric.unwrap_or_default();
+    let connections = args.connections.unwrap_or(boxes.connections);
+    let top = args.top.unwrap_or(TOP_CIRCUITS);
+    let sizes = circuit_sizes(positions, connections, metric);
+    let product = sizes.iter().take(top).product::<usize>();
+    let size_list = sizes
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<String>>()
+        .join(", ");
+    let mut lines = vec![
+        format!("Circuit sizes after {connections} connections: {size_list}"),
+        format!("Product of the {top} largest: {product}"),
+    ];
+    if args.metric.is_some()
+        && let Some((first, second)) = last_connection(positions, metric)
+    {
+        lines.push(format!("Last connection: {first} and {second}"));
+    }
+    Ok(Some(lines.join("\n")))
 }
 
-/// Solves part 1 of day 8
-#[expect(
-    unused_must_use,
-    reason = "The error would only be part of a debug print."
-)]
-fn count_connected(positions: &[Position3D], num: usize) -> usize {
-    let distances = distance_matrix(positions);
-    let shortest = shortest_distances(&distances);
-    let mut group_ids: HashMap<&Position3D, usize> = HashMap::new();
-    let mut groups: HashMap<usize, HashSet<&Position3D>> = HashMap::new();
-    let mut connections: usize = 0;
-    for (pos1, pos2) in shortest {
-        if connections >= num {
-            // Only add `num` connections
-            break;
-        }
-        connections = connections.saturating_add(1);
-        let group_id_1 = *group_ids.entry(pos1).or_insert(connections);
-        let group_id_2 = *group_ids.entry(pos2).or_insert(connections);
-        // Skip connections within the same group
-        if group_id_1 != connections && group_id_1 == group_id_2 {
-            debug!(
-                "Skipping connection between {pos1:?} and {pos2:?} because both have group ID {group_id_1}."
-            );
-            continue;
-        }
-        // Merge groups: All group IDs of group2 have to be set to the ID of group1
-        info!(
-            "Connecting {pos1:?} and {pos2:?}, merging group {group_id_2} into group {group_id_1}."
-        );
-        let removed = groups
-            .remove(&group_id_2)
-            .unwrap_or_else(|| HashSet::from_iter(vec![pos2]));
-        let mut removed_sorted = removed.iter().collect::<Vec<&&Position3D>>();
-        removed_sorted.sort();
-        let group1_set = groups
-            .entry(group_id_1)
-            .or_insert_with(|| HashSet::from_iter(vec![pos1]));
-        for pos_to_update in removed_sorted {
-            group1_set.insert(pos_to_update);
-            group_ids.insert(pos_to_update, group_id_1);
-        }
-        let sizes = groups.iter().fold(String::new(), |mut acc, (id, grp)| {
-            let grp_len = grp.len();
-            write!(acc, "{id}: {grp_len}, ");
-            acc
-        });
-        info!("Group sizes: {sizes}");
-        info!("Added {connections} connections.");
-        debug!("Groups: {groups:?}");
-        debug!("Group IDs: {group_ids:?}");
-    }
-    // Get group sizes and multiply the three largest ones.
-    let mut sizes = groups
-        .values()
-        .map(collections::HashSet::len)
-        .collect::<Vec<usize>>();
-    // sort in descending order
-    sizes.sort_by_key(|val| usize::MAX.saturating_sub(*val));
-    sizes.iter().take(3).product()
+/// Lists the junction boxes closest to a coordinate with their distances
+fn find_closest(
+    args: &CircuitArgs,
+    coordinate: &str,
+    positions: &[Position3D],
+) -> Result<String, String> {
+    let target = parse_input(coordinate)
+        .map_err(|err| err.to_string())?
+        .into_iter()
+        .next()
+        .ok_or_else(|| "The coordinate must be given as X,Y,Z!".to_owned())?;
+    let metric = args.metric.unwrap_or_default();
+    let index = KdTree::new(positions, metric);
+    let found = args.radius.map_or_else(
+        || index.nearest(&target, args.neighbors),
+        |radius| index.within(&target, metric.distance_for_length(radius)),
+    );
+    let mut lines = vec![format!("Junction boxes closest to {target}:")];
+    lines.extend(found.iter().filter_map(|&(distance, idx)| {
+        let length = metric.length(distance);
+        Some(format!("{} (distance {length:.2})", positions.get(idx)?))
+    }));
+    Ok(lines.join("\n"))
 }
 
-#[expect(
-    clippy::print_stdout,
-    clippy::print_stderr,
-    reason = "This is a CLI function."
-)]
 fn main() {
-    env_logger::init();
-    let input_path_str = args()
-        .nth(1)
-        .unwrap_or_else(|| "sample_input.txt".to_owned());
-    let input_path = Path::new(&input_path_str);
-    let contents: String = match fs::read_to_string(input_path) {
-        Ok(str) => str,
-        Err(err) => {
-            let input_disp = input_path.display();
-            eprintln!("Could not read {input_disp}!\nReason: Err({err})");
-            exit(1);
-        }
-    };
-    let input = match parse_input(&contents) {
-        Ok(inp) => inp,
-        Err(err) => {
-            eprint!("Could not parse input! Reason:\n{err}");
-            exit(1);
-        }
-    };
-    info!("Parsed input: {input:?}");
-    let result = count_connected(&input, 1000);
-    println!("Result: {result}");
+    run_cli_with::<Day08, CircuitArgs>("sample_input.txt", run_circuits);
 }
@@ -0,0 +1,28 @@
+//! Tests for the command line options of the day 8 binary
+
+use std::process::Command;
+
+/// Runs the binary on the sample input with the given options and returns what it printed
+fn run_on_sample(options: &[&str]) -> Result<String, String> {
+    let output = Command::new(env!("CARGO_BIN_EXE_day08"))
+        .arg(concat!(env!("CARGO_MANIFEST_DIR"), "/sample_input.txt"))
+        .args(options)
+        .output()
+        .map_err(|err| format!("Could not run the binary!\nReason: {err}"))?;
+    if !output.status.success() {
+        return Err(String::from_utf8_lossy(&output.stderr).into_owned());
+    }
+    String::from_utf8(output.stdout).map_err(|err| err.to_string())
+}
+
+/// Checks that a coordinate starting with a minus sign is not taken for an option
+#[test]
+fn closest_accepts_negative_coordinates() {
+    assert_eq!(
+        run_on_sample(&["--closest", "-5,-10,0", "--neighbors", "2"])
+            .expect("the options are accepted"),
+        "Junction boxes closest to -5,-10,0:\n\
+         117,168,530 (distance 572.25)\n\
+         352,342,300 (distance 584.25)\n"
+    );
+}
@@ -0,0 +1,26 @@
+//! Tests for connecting all junction boxes into a single circuit
+
+use aoc_common::metric::Euclidean;
+use day08::{minimum_spanning_tree, parse_input};
+
+/// Checks that the spanning tree of the sample connects every junction box, shortest first
+#[test]
+fn sample_spanning_tree() {
+    let content = include_str!("../sample_input.txt");
+    let positions = parse_input(content).expect("the sample is valid");
+    let tree = minimum_spanning_tree(&positions, Euclidean);
+    assert_eq!(tree.len(), positions.len().saturating_sub(1));
+    assert!(tree.windows(2).all(
+        |pair| pair.first().map(|edge| edge.distance) <= pair.get(1).map(|edge| edge.distance)
+    ));
+    let mut connected = vec![false; positions.len()];
+    for edge in &tree {
+        for node in [edge.first, edge.second] {
+            if let Some(seen) = connected.get_mut(node) {
+                *seen = true;
+            }
+        }
+    }
+    assert!(connected.iter().all(|&seen| seen));
+    assert!(minimum_spanning_tree(positions.get(..1).unwrap_or_default(), Euclidean).is_empty());
+}
@@ -0,0 +1,216 @@
+//! Solves day 9 of Advent of Code 2025
+
+pub mod polygon;
+pub mod staircase;
+pub mod svg;
+
+use aoc_common::geometry::Point2;
+use aoc_common::input::ParseError;
+use aoc_common::solution::Solution;
+use log::{debug, info};
+use polygon::Interior;
+
+/// Represents a 2D Cartesian coordinate of a tile
+pub type Position2D = Point2<i64>;
+
+/// Rectangle between two red tiles in opposite corners
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Rectangle {
+    /// Number of tiles in the rectangle, including its border
+    pub area: u64,
+    /// One of the corner tiles
+    pub first: Position2D,
+    /// The opposite corner tile
+    pub second: Position2D,
+}
+
+impl Rectangle {
+    /// Creates the rectangle between two opposite corner tiles
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the area does not fit into a `u64`.
+    pub fn new(first: Position2D, second: Position2D) -> Result<Self, String> {
+        let area = rectangle_area(&first, &second).ok_or_else(|| {
+            format!("The area of the rectangle between {first} and {second} is too large!")
+        })?;
+        Ok(Self {
+            area,
+            first,
+            second,
+        })
+    }
+}
+
+/// Solution for day 9
+#[derive(Debug)]
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: u8 = 9;
+
+    type Input = Vec<Position2D>;
+
+    fn parse_input(content: &str) -> Result<Self::Input, ParseError> {
+        parse_input(content)
+    }
+
+    fn part1(input: &Self::Input) -> Result<String, String> {
+        largest_rectangle(input).map(|area| area.to_string())
+    }
+
+    fn part2(input: &Self::Input) -> Result<String, String> {
+        largest_enclosed_rectangle(input).map(|area| area.to_string())
+    }
+}
+
+/// Parses input for day 9
+///
+/// # Errors
+///
+/// Returns an error if a line does not contain exactly two numbers.
+pub fn parse_input(content: &str) -> Result<Vec<Position2D>, ParseError> {
+    content
+        .lines()
+        .enumerate()
+        .map(|(idx, line)| Position2D::parse(idx, line))
+        .collect()
+}
+
+/// Calculates the area that a rectangle between two opposite corner tiles would span
+///
+/// Both corner tiles are part of the rectangle. Returns `None` if the area does not fit into a `u64`.
+#[must_use]
+pub fn rectangle_area(first: &Position2D, second: &Position2D) -> Option<u64> {
+    let width = first.x.abs_diff(second.x).checked_add(1)?;
+    let height = first.y.abs_diff(second.y).checked_add(1)?;
+    width.checked_mul(height)
+}
+
+/// Solves part 1
+///
+/// Only pairs the tiles on the outer staircases of all red tiles, see [`staircase`].
+///
+/// # Errors
+///
+/// Returns an error if the area of the largest rectangle does not fit into a `u64`.
+///
+/// # Examples
+///
+/// ```
+/// use day09::{largest_rectangle, parse_input};
+///
+/// let tiles = parse_input("7,1\n11,7\n-9, 5")?;
+/// assert_eq!(largest_rectangle(&tiles), Ok(85));
+/// # Ok::<(), aoc_common::input::ParseError>(())
+/// ```
+pub fn largest_rectangle(input: &[Position2D]) -> Result<u64, String> {
+    Ok(best_rectangle(input)?.map_or(0, |rect| rect.area))
+}
+
+/// Finds the largest rectangle between any two red tiles for part 1
+///
+/// Returns `None` if there are less than two distinct red tiles.
+///
+/// # Errors
+///
+/// Returns an error if the area of the largest rectangle does not fit into a `u64`.
+pub fn best_rectangle(input: &[Position2D]) -> Result<Option<Rectangle>, String> {
+    let Some((pos1, pos2)) = staircase::largest_pair(input) else {
+        return Ok(None);
+    };
+    let rect = Rectangle::new(pos1, pos2)?;
+    info!(
+        "The largest rectangle between {pos1} and {pos2} has an area of {}.",
+        rect.area
+    );
+    Ok(Some(rect))
+}
+
+/// Solves part 1 by examining all pairs of red tiles in O(n²) time
+///
+/// This is the reference for [`largest_rectangle`].
+///
+/// # Errors
+///
+/// Returns an error if the area of a rectangle does not fit into a `u64`.
+pub fn largest_rectangle_brute_force(input: &[Position2D]) -> Result<u64, String> {
+    let mut max_rect: u64 = 0;
+    for pos1 in input {
+        for pos2 in input {
+            // Limit comparisons to triangle
+            if pos1 >= pos2 {
+                continue;
+            }
+            let area = rectangle_area(pos1, pos2).ok_or_else(|| {
+                format!("The area of the rectangle between {pos1} and {pos2} is too large!")
+            })?;
+            if area > max_rect {
+                max_rect = area;
+                debug!(
+                    "Examining rectangle between {pos1} and {pos2}, new maximum area: {max_rect}."
+                );
+            }
+        }
+    }
+    Ok(max_rect)
+}
+
+/// Solves part 2
+///
+/// The red tiles outline a loop in their order, and all tiles on or inside of it are red or
+/// green. Finds the largest rectangle with two red tiles in opposite corners that only
+/// contains red and green tiles.
+///
+/// # Errors
+///
+/// Returns an error if two consecutive red tiles are not in the same row or column or if the
+/// area of a rectangle does not fit into a `u64`.
+///
+/// # Examples
+///
+/// ```
+/// use day09::{largest_enclosed_rectangle, parse_input};
+///
+/// let tiles = parse_input("1,1\n5,1\n5,3\n3,3\n3,6\n1,6")?;
+/// assert_eq!(largest_enclosed_rectangle(&tiles), Ok(18));
+/// # Ok::<(), aoc_common::input::ParseError>(())
+/// ```
+pub fn largest_enclosed_rectangle(input: &[Position2D]) -> Result<u64, String> {
+    Ok(best_enclosed_rectangle(input)?.map_or(0, |rect| rect.area))
+}
+
+/// Finds the largest rectangle inside the loop of red tiles for part 2
+///
+/// Returns `None` if there are less than two red tiles.
+///
+/// # Errors
+///
+/// Returns an error if two consecutive red tiles are not in the same row or column or if the
+/// area of a rectangle does not fit into a `u64`.
+pub fn best_enclosed_rectangle(input: &[Position2D]) -> Result<Option<Rectangle>, String> {
+    let interior = Interior::new(input)?;
+    let mut best: Option<Rectangle> = None;
+    for (idx, pos1) in input.iter().enumerate() {
+        for pos2 in input.iter().skip(idx.saturating_add(1)) {
+            let rect = Rectangle::new(*pos1, *pos2)?;
+            // the containment test is cheap, but skipping it for smaller areas is even cheaper
+            if best.is_none_or(|max_rect| rect.area > max_rect.area)
+                && interior.contains_rectangle(pos1, pos2)
+            {
+                debug!(
+                    "Found rectangle between {pos1} and {pos2} with area {}.",
+                    rect.area
+                );
+                best = Some(rect);
+            }
+        }
+    }
+    if let Some(rect) = best {
+        info!(
+            "The largest rectangle inside the loop has an area of {}.",
+            rect.area
+        );
+    }
+    Ok(best)
+}
@@ -1,93 +1,40 @@
-//! Solves day 9 of Advent of Code 2025
-use core::num::ParseIntError;
-use std::{env::args, fs, path::Path, process::exit};
+//! Runs the solution for day 9 of Advent of Code 2025
 
-use log::info;
+use std::fs;
+use std::path::PathBuf;
 
-/// Represents a 2D Cartesian coordinate of a tile
-#[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
-struct Position2D {
-    /// Position on x-axis
-    x: usize,
-    /// Position on y-axis
-    y: usize,
-}
-
-impl Position2D {
-    /// Calculates the area that a rectangle between this and another position would span
-    const fn rectangle_area(&self, other: &Self) -> usize {
-        self.x
-            .abs_diff(other.x)
-            .saturating_add(1)
-            .saturating_mul(self.y.abs_diff(other.y).saturating_add(1))
-    }
-}
+use aoc_common::solution::run_cli_with;
+use clap::Args;
+use day09::{Day09, Position2D, best_enclosed_rectangle, best_rectangle, svg};
+use log::warn;
 
-/// Parses input for day 9
-fn parse_input(content: &str) -> Result<Vec<Position2D>, String> {
-    content
-        .lines()
-        .map(|line| {
-            let Ok(numbers) = line
-                .split(',')
-                .map(str::parse::<usize>)
-                .collect::<Result<Vec<usize>, ParseIntError>>()
-            else {
-                return Err(format!("Could not parse numbers in line {line}"));
-            };
-            let &[x, y] = numbers.as_slice() else {
-                return Err(format!("Wrong number of coordinates: {numbers:?}"));
-            };
-            Ok(Position2D { x, y })
-        })
-        .collect()
+/// Arguments for drawing the tiles instead of solving the puzzle
+#[derive(Debug, Args)]
+struct DrawingArgs {
+    /// Draw the red tiles, their loop and the largest rectangles of both parts to this SVG file
+    #[arg(long, value_name = "FILE")]
+    svg: Option<PathBuf>,
 }
 
-/// Solves part 1
-fn largest_rectangle(input: &[Position2D]) -> usize {
-    let mut max_rect: usize = 0;
-    for pos1 in input {
-        for pos2 in input {
-            // Limit comparisons to triangle
-            if pos1 >= pos2 {
-                continue;
-            }
-            max_rect = max_rect.max(pos1.rectangle_area(pos2));
-            info!(
-                "Examining rectangle between {pos1:?} and {pos2:?}, new maximum area: {max_rect}."
-            );
-        }
-    }
-    max_rect
+/// Draws the tiles if a file is given
+#[expect(clippy::ptr_arg, reason = "The signature is given by `ExtraHandler`.")]
+fn run_drawing(args: &DrawingArgs, tiles: &Vec<Position2D>) -> Result<Option<String>, String> {
+    let Some(output) = args.svg.as_ref() else {
+        return Ok(None);
+    };
+    let largest = best_rectangle(tiles)?;
+    // an invalid loop is worth seeing, too
+    let enclosed = best_enclosed_rectangle(tiles).unwrap_or_else(|err| {
+        warn!("Drawing no rectangle for part 2: {err}");
+        None
+    });
+    let drawing = svg::render(tiles, largest.as_ref(), enclosed.as_ref());
+    let path = output.display();
+    fs::write(output, drawing)
+        .map_err(|err| format!("Could not write the drawing to {path}!\nReason: {err}"))?;
+    Ok(Some(format!("Saved the drawing to {path}")))
 }
 
-#[expect(
-    clippy::print_stdout,
-    clippy::print_stderr,
-    reason = "This is a CLI function."
-)]
 fn main() {
-    env_logger::init();
-    let input_path_str = args()
-        .nth(1)
-        .unwrap_or_else(|| "sample_input.txt".to_owned());
-    let input_path = Path::new(&input_path_str);
-    let contents: String = match fs::read_to_string(input_path) {
-        Ok(str) => str,
-        Err(err) => {
-            let input_disp = input_path.display();
-            eprintln!("Could not read {input_disp}!\nReason: Err({err})");
-            exit(1);
-        }
-    };
-    let input = match parse_input(&contents) {
-        Ok(inp) => inp,
-        Err(err) => {
-            eprint!("Could not parse input! Reason:\n{err}");
-            exit(1);
-        }
-    };
-    info!("Parsed input: {input:?}");
-    let result = largest_rectangle(&input);
-    println!("Result: {result}");
+    run_cli_with::<Day09, DrawingArgs>("sample_input.txt", run_drawing);
 }
@@ -0,0 +1,192 @@
+//! Interior of the loop of red tiles on a compressed grid
+//!
+//! Only the rows and columns that contain a red tile matter, so every distinct coordinate gets
+//! its own cell and every gap between two neighboring coordinates is collapsed into a single
+//! cell. Neighboring coordinates without anything between them get no gap cell, otherwise the
+//! gap would connect the outside with the inside between two adjacent walls. A frame of outside
+//! cells surrounds the grid, so a flood fill from its corner finds everything outside the loop.
+//! Prefix sums over the outside cells then tell in constant time whether a rectangle contains
+//! any of them.
+
+use core::ops::RangeInclusive;
+
+use aoc_common::grid::Grid;
+use log::debug;
+
+use crate::Position2D;
+
+/// Range of rows and range of columns of the compressed grid
+type CellSpan = (RangeInclusive<usize>, RangeInclusive<usize>);
+
+/// Distinct coordinates of the red tiles along one axis with their cells in the compressed grid
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct CompressedAxis {
+    /// Cell of each coordinate, after the frame and the gap cells before it
+    cells: Vec<usize>,
+    /// Distinct coordinates in ascending order
+    coordinates: Vec<i64>,
+    /// Number of cells, including the frame on both sides
+    size: usize,
+}
+
+/// Tiles inside or on the loop of red tiles, i.e. the red and green tiles
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Interior {
+    /// Compressed x coordinates of the red tiles
+    columns: CompressedAxis,
+    /// Number of outside cells above and left of each cell, with an extra first row and column
+    outside_before: Grid<usize>,
+    /// Compressed y coordinates of the red tiles
+    rows: CompressedAxis,
+}
+
+impl CompressedAxis {
+    /// Cell of the compressed grid that holds the given coordinate
+    fn cell(&self, coordinate: i64) -> Option<usize> {
+        let idx = self.coordinates.binary_search(&coordinate).ok()?;
+        self.cells.get(idx).copied()
+    }
+
+    /// Compresses the distinct values, with a gap cell between two neighboring coordinates only
+    /// if there are coordinates between them
+    fn new(values: impl Iterator<Item = i64>) -> Self {
+        let coordinates = distinct_sorted(values);
+        let mut cells = Vec::with_capacity(coordinates.len());
+        // the first cell belongs to the frame
+        let mut next_cell = 1_usize;
+        for (idx, &coordinate) in coordinates.iter().enumerate() {
+            let previous = idx.checked_sub(1).and_then(|prev| coordinates.get(prev));
+            if previous.is_some_and(|&prev| coordinate.abs_diff(prev) > 1) {
+                next_cell = next_cell.saturating_add(1);
+            }
+            cells.push(next_cell);
+            next_cell = next_cell.saturating_add(1);
+        }
+        Self {
+            cells,
+            coordinates,
+            size: next_cell.saturating_add(1),
+        }
+    }
+}
+
+impl Interior {
+    /// Returns whether the rectangle between two opposite corner tiles only contains red and
+    /// green tiles
+    ///
+    /// Corners that are not on a row and column of a red tile are never contained.
+    #[must_use]
+    pub fn contains_rectangle(&self, first: &Position2D, second: &Position2D) -> bool {
+        let Some((rows, columns)) = cell_span(&self.rows, &self.columns, *first, *second) else {
+            return false;
+        };
+        let (top, bottom) = (*rows.start(), rows.end().saturating_add(1));
+        let (left, right) = (*columns.start(), columns.end().saturating_add(1));
+        let count = |row, col| self.outside_before.get(row, col).copied().unwrap_or(0);
+        // inclusion-exclusion, ordered so that no intermediate result is negative
+        count(bottom, right)
+            .saturating_add(count(top, left))
+            .saturating_sub(count(top, right))
+            .saturating_sub(count(bottom, left))
+            == 0
+    }
+
+    /// Traces the loop through the red tiles in their order and fills its inside
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if two consecutive red tiles, including the last and the first one,
+    /// are not in the same row or column.
+    pub fn new(tiles: &[Position2D]) -> Result<Self, String> {
+        let columns = CompressedAxis::new(tiles.iter().map(|tile| tile.x));
+        let rows = CompressedAxis::new(tiles.iter().map(|tile| tile.y));
+        let mut border = Grid::filled(rows.size, columns.size, false);
+        for (from, to) in tiles.iter().zip(tiles.iter().cycle().skip(1)) {
+            if from.x != to.x && from.y != to.y {
+                return Err(format!(
+                    "The red tiles {from} and {to} are not in the same row or column!"
+                ));
+            }
+            let Some((edge_rows, edge_columns)) = cell_span(&rows, &columns, *from, *to) else {
+                continue;
+            };
+            let cells = edge_rows.flat_map(|row| edge_columns.clone().map(move |col| (row, col)));
+            for (row, col) in cells {
+                if let Some(cell) = border.get_mut(row, col) {
+                    *cell = true;
+                }
+            }
+        }
+        let outside = fill_outside(&border);
+        debug!(
+            "Compressed {} red tiles into {} rows and {} columns.",
+            tiles.len(),
+            rows.size,
+            columns.size
+        );
+        Ok(Self {
+            columns,
+            outside_before: prefix_sums(&outside),
+            rows,
+        })
+    }
+}
+
+/// Rows and columns of the compressed grid that are covered by the rectangle between two
+/// opposite corners, or `None` if a corner is not on a compressed row and column
+fn cell_span(
+    rows: &CompressedAxis,
+    columns: &CompressedAxis,
+    first: Position2D,
+    second: Position2D,
+) -> Option<CellSpan> {
+    let (from_row, to_row) = (rows.cell(first.y)?, rows.cell(second.y)?);
+    let (from_col, to_col) = (columns.cell(first.x)?, columns.cell(second.x)?);
+    Some((
+        from_row.min(to_row)..=from_row.max(to_row),
+        from_col.min(to_col)..=from_col.max(to_col),
+    ))
+}
+
+/// Distinct values in ascending order
+fn distinct_sorted(values: impl Iterator<Item = i64>) -> Vec<i64> {
+    let mut sorted = values.collect::<Vec<i64>>();
+    sorted.sort_unstable();
+    sorted.dedup();
+    sorted
+}
+
+/// Marks all cells that can be reached from the frame without crossing the border
+fn fill_outside(border: &Grid<bool>) -> Grid<bool> {
+    let mut outside = Grid::filled(border.rows(), border.columns(), false);
+    let mut stack = vec![(0, 0)];
+    while let Some((row, col)) = stack.pop() {
+        if border.get(row, col) != Some(&false) {
+            continue;
+        }
+        let Some(cell) = outside.get_mut(row, col).filter(|cell| !**cell) else {
+            continue;
+        };
+        *cell = true;
+        stack.extend(border.neighbors4(row, col).map(|(pos, _)| pos));
+    }
+    outside
+}
+
+/// Counts the marked cells above and left of each cell, with an extra first row and column
+fn prefix_sums(marked: &Grid<bool>) -> Grid<usize> {
+    let rows = marked.rows().saturating_add(1);
+    let mut sums = Grid::filled(rows, marked.columns().saturating_add(1), 0);
+    let sum = |grid: &Grid<usize>, at_row, at_col| grid.get(at_row, at_col).copied().unwrap_or(0);
+    for ((row, col), &is_marked) in marked.iter() {
+        let (below, right) = (row.saturating_add(1), col.saturating_add(1));
+        let value = sum(&sums, row, right)
+            .saturating_add(sum(&sums, below, col))
+            .saturating_sub(sum(&sums, row, col))
+            .saturating_add(usize::from(is_marked));
+        if let Some(cell) = sums.get_mut(below, right) {
+            *cell = value;
+        }
+    }
+    sums
+}
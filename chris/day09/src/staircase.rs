@@ -0,0 +1,124 @@
+//! Largest rectangle between two red tiles in O(n log n) time
+//!
+//! If a rectangle has its corners in the lower left and the upper right, replacing the lower
+//! left corner with a tile that is further left and further down only makes it larger. So only
+//! the tiles on the lower left staircase, which have no other tile to their lower left, and the
+//! tiles on the upper right staircase need to be paired. Along both staircases x increases and
+//! y decreases, and the best partner on the upper staircase moves right as the corner on the
+//! lower staircase moves right, which allows a divide and conquer search. Rectangles with their
+//! corners in the upper left and the lower right are found the same way after mirroring.
+
+use core::cmp::Reverse;
+
+use log::debug;
+
+use crate::Position2D;
+
+/// Pair of corner tiles with the area of their rectangle, which may overflow an `i64`
+type Candidate = (i128, Position2D, Position2D);
+
+/// Finds the two red tiles that span the largest rectangle
+///
+/// Returns `None` if there are less than two distinct tiles or if a coordinate cannot be mirrored.
+#[must_use]
+pub fn largest_pair(tiles: &[Position2D]) -> Option<(Position2D, Position2D)> {
+    let mut distinct = tiles.to_vec();
+    distinct.sort_unstable();
+    distinct.dedup();
+    if distinct.len() < 2 {
+        return None;
+    }
+    let mirrored = distinct
+        .iter()
+        .map(|tile| Some(Position2D::new(tile.x, tile.y.checked_neg()?)))
+        .collect::<Option<Vec<Position2D>>>()?;
+    let (area, first, second) =
+        best_diagonal(&distinct).max(best_diagonal(&mirrored).map(|(area, first, second)| {
+            (
+                area,
+                Position2D::new(first.x, first.y.saturating_neg()),
+                Position2D::new(second.x, second.y.saturating_neg()),
+            )
+        }))?;
+    debug!("The rectangle between {first} and {second} has the largest area {area}.");
+    Some((first, second))
+}
+
+/// Area of the rectangle between two tiles, which is not positive if `upper` is not to the
+/// upper right of `lower`
+fn area(lower: Position2D, upper: Position2D) -> i128 {
+    let side = |from: i64, to: i64| {
+        i128::from(to)
+            .saturating_sub(i128::from(from))
+            .saturating_add(1)
+    };
+    side(lower.x, upper.x).saturating_mul(side(lower.y, upper.y))
+}
+
+/// Finds the largest rectangle with one tile in the lower left and the other in the upper right
+fn best_diagonal(tiles: &[Position2D]) -> Option<Candidate> {
+    let lower = lower_staircase(tiles);
+    let upper = upper_staircase(tiles);
+    debug!(
+        "Pairing {} tiles on the lower staircase with {} tiles on the upper one.",
+        lower.len(),
+        upper.len()
+    );
+    search(&lower, &upper, 0, upper.len().saturating_sub(1))
+}
+
+/// Tiles without another tile to their lower left, ordered by increasing x and decreasing y
+fn lower_staircase(tiles: &[Position2D]) -> Vec<Position2D> {
+    let mut sorted = tiles.to_vec();
+    sorted.sort_unstable();
+    let mut staircase = Vec::<Position2D>::new();
+    for tile in sorted {
+        if staircase.last().is_none_or(|last| tile.y < last.y) {
+            staircase.push(tile);
+        }
+    }
+    staircase
+}
+
+/// Finds the best partner on `upper` for every tile on `lower`, knowing that the partners of
+/// all tiles on `lower` are between `first` and `last`
+///
+/// The best partner of the middle tile splits the range of partners for both halves.
+fn search(
+    lower: &[Position2D],
+    upper: &[Position2D],
+    first: usize,
+    last: usize,
+) -> Option<Candidate> {
+    let middle = 0_usize.midpoint(lower.len());
+    let corner = *lower.get(middle)?;
+    let (partner_idx, partner) = upper
+        .iter()
+        .enumerate()
+        .take(last.saturating_add(1))
+        .skip(first)
+        // the first of several equally good partners keeps the split consistent
+        .min_by_key(|&(_, &partner)| Reverse(area(corner, partner)))?;
+    let best = (area(corner, *partner), corner, *partner);
+    let before = lower
+        .get(..middle)
+        .and_then(|half| search(half, upper, first, partner_idx));
+    let after = lower
+        .get(middle.saturating_add(1)..)
+        .and_then(|half| search(half, upper, partner_idx, last));
+    Some(before.max(after).map_or(best, |other| other.max(best)))
+}
+
+/// Tiles without another tile to their upper right, ordered by increasing x and decreasing y
+fn upper_staircase(tiles: &[Position2D]) -> Vec<Position2D> {
+    let mut sorted = tiles.to_vec();
+    sorted.sort_unstable_by(|first, second| second.cmp(first));
+    let mut staircase = Vec::<Position2D>::new();
+    for tile in sorted {
+        if staircase.last().is_none_or(|last| tile.y > last.y) {
+            staircase.push(tile);
+        }
+    }
+    staircase.reverse();
+    staircase
+}
@@ -0,0 +1,179 @@
+//! Drawing of the red tiles, their loop and the largest rectangles as SVG image
+//!
+//! The coordinates of the puzzle are scaled so that the longer side of the image has a fixed
+//! size. Every tile becomes a square of at least one pixel, so even the thinnest rectangle stays
+//! visible.
+
+use crate::{Position2D, Rectangle};
+
+/// Size of the longer side of the drawing in pixels, without the margin
+const CANVAS: i128 = 800;
+
+/// Offset of the baseline of a label from the upper edge of its rectangle to be above it
+const LABEL_ABOVE: i128 = -6;
+
+/// Offset of the baseline of a label from the upper edge of its rectangle to be inside of it
+const LABEL_INSIDE: i128 = 18;
+
+/// Margin around the drawing in pixels, which leaves room for the labels
+const MARGIN: i128 = 40;
+
+/// Radius of the dots that mark the red tiles in pixels
+const TILE_RADIUS: i128 = 3;
+
+/// Maps puzzle coordinates to pixels
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+struct Scale {
+    /// Smallest x coordinate of all tiles
+    min_x: i128,
+    /// Smallest y coordinate of all tiles
+    min_y: i128,
+    /// Number of tiles along the longer side of the drawing
+    span: i128,
+}
+
+impl Scale {
+    /// Pixel of the center of a tile
+    fn center(&self, tile: Position2D) -> (i128, i128) {
+        let (left, top) = self.corner(tile);
+        let half = self.tile_size().checked_div(2).unwrap_or(0);
+        (left.saturating_add(half), top.saturating_add(half))
+    }
+
+    /// Pixel of the upper left corner of a tile
+    fn corner(&self, tile: Position2D) -> (i128, i128) {
+        (
+            self.pixels(i128::from(tile.x).saturating_sub(self.min_x)),
+            self.pixels(i128::from(tile.y).saturating_sub(self.min_y)),
+        )
+    }
+
+    /// Fits all tiles into the canvas
+    fn fitting(tiles: &[Position2D]) -> Self {
+        let xs = tiles.iter().map(|tile| i128::from(tile.x));
+        let ys = tiles.iter().map(|tile| i128::from(tile.y));
+        let (min_x, max_x) = (xs.clone().min().unwrap_or(0), xs.max().unwrap_or(0));
+        let (min_y, max_y) = (ys.clone().min().unwrap_or(0), ys.max().unwrap_or(0));
+        let span = max_x
+            .saturating_sub(min_x)
+            .max(max_y.saturating_sub(min_y))
+            .saturating_add(1);
+        Self { min_x, min_y, span }
+    }
+
+    /// Offset in pixels from the margin for an offset in tiles
+    fn pixels(&self, tiles: i128) -> i128 {
+        let scaled = tiles
+            .saturating_mul(CANVAS)
+            .checked_div(self.span)
+            .unwrap_or(0);
+        MARGIN.saturating_add(scaled)
+    }
+
+    /// Size of a single tile in pixels, which is at least one pixel
+    fn tile_size(&self) -> i128 {
+        self.pixels(1).saturating_sub(MARGIN).max(1)
+    }
+}
+
+/// Draws the red tiles, the loop through them and the largest rectangles of both parts
+///
+/// The rectangle of part 1 is dashed and the one of part 2 is filled, each labeled with its area.
+#[must_use]
+pub fn render(
+    tiles: &[Position2D],
+    largest: Option<&Rectangle>,
+    enclosed: Option<&Rectangle>,
+) -> String {
+    let scale = Scale::fitting(tiles);
+    let (right, bottom) = tiles
+        .iter()
+        .map(|tile| scale.corner(*tile))
+        .fold((MARGIN, MARGIN), |(right, bottom), (left, top)| {
+            (right.max(left), bottom.max(top))
+        });
+    let padding = scale.tile_size().saturating_add(MARGIN);
+    let (width, height) = (
+        right.saturating_add(padding),
+        bottom.saturating_add(padding),
+    );
+    let mut lines = vec![
+        format!(
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{width}\" height=\"{height}\" \
+             viewBox=\"0 0 {width} {height}\">"
+        ),
+        "  <rect width=\"100%\" height=\"100%\" fill=\"white\"/>".to_owned(),
+    ];
+    let points = tiles
+        .iter()
+        .map(|tile| {
+            let (x, y) = scale.center(*tile);
+            format!("{x},{y}")
+        })
+        .collect::<Vec<String>>()
+        .join(" ");
+    lines.push(format!(
+        "  <polygon points=\"{points}\" fill=\"#c8e6c9\" stroke=\"#2e7d32\" stroke-width=\"1\"/>"
+    ));
+    if let Some(rect) = largest {
+        lines.extend(rectangle(
+            &scale,
+            rect,
+            ("Part 1", LABEL_ABOVE),
+            "#1565c0",
+            "fill=\"none\" stroke-dasharray=\"8 4\"",
+        ));
+    }
+    if let Some(rect) = enclosed {
+        lines.extend(rectangle(
+            &scale,
+            rect,
+            ("Part 2", LABEL_INSIDE),
+            "#e65100",
+            "fill=\"#ffeb3b\" fill-opacity=\"0.6\"",
+        ));
+    }
+    for tile in tiles {
+        let (x, y) = scale.center(*tile);
+        lines.push(format!(
+            "  <circle cx=\"{x}\" cy=\"{y}\" r=\"{TILE_RADIUS}\" fill=\"#d32f2f\"><title>{tile}</title></circle>"
+        ));
+    }
+    lines.push("</svg>\n".to_owned());
+    lines.join("\n")
+}
+
+/// Draws a rectangle outlined in `color` and filled as given by `style`, and labels it with its
+/// corners and its area at the given offset from its upper edge
+fn rectangle(
+    scale: &Scale,
+    rect: &Rectangle,
+    (label, offset): (&str, i128),
+    color: &str,
+    style: &str,
+) -> [String; 2] {
+    let (first_x, first_y) = scale.corner(rect.first);
+    let (second_x, second_y) = scale.corner(rect.second);
+    let (left, top) = (first_x.min(second_x), first_y.min(second_y));
+    let tile_size = scale.tile_size();
+    let width = first_x
+        .abs_diff(second_x)
+        .saturating_add(tile_size.unsigned_abs());
+    let height = first_y
+        .abs_diff(second_y)
+        .saturating_add(tile_size.unsigned_abs());
+    [
+        format!(
+            "  <rect x=\"{left}\" y=\"{top}\" width=\"{width}\" height=\"{height}\" {style} \
+             stroke=\"{color}\" stroke-width=\"2\"/>"
+        ),
+        format!(
+            "  <text x=\"{left}\" y=\"{}\" font-family=\"sans-serif\" font-size=\"14\" \
+             fill=\"{color}\">{label}: {} and {}, area {}</text>",
+            top.saturating_add(offset),
+            rect.first,
+            rect.second,
+            rect.area
+        ),
+    ]
+}
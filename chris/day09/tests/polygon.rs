@@ -0,0 +1,96 @@
+//! Tests for the rectangles inside the loop of red tiles against a check of every single tile
+
+use day09::polygon::Interior;
+use day09::{Position2D, largest_enclosed_rectangle, parse_input, rectangle_area};
+
+/// Loops of red tiles with notches, including negative coordinates and adjacent walls
+const LOOPS: [&str; 6] = [
+    "7,1\n11,1\n11,7\n9,7\n9,5\n2,5\n2,3\n7,3",
+    "1,1\n5,1\n5,3\n3,3\n3,6\n1,6",
+    "0,0\n9,0\n9,6\n6,6\n6,2\n3,2\n3,6\n0,6",
+    "-4,-4\n4,-4\n4,4\n2,4\n2,-2\n-2,-2\n-2,4\n-4,4",
+    "0,0\n10,0\n10,10\n4,10\n4,2\n3,2\n3,10\n0,10",
+    "0,0\n6,0\n6,1\n1,1\n1,2\n6,2\n6,3\n0,3",
+];
+
+/// Returns whether a tile is on the loop or inside of it
+fn is_inside(tiles: &[Position2D], tile: Position2D) -> bool {
+    let edges = tiles.iter().zip(tiles.iter().cycle().skip(1));
+    let mut crossings = 0_usize;
+    for (from, to) in edges {
+        let (min_x, max_x) = (from.x.min(to.x), from.x.max(to.x));
+        let (min_y, max_y) = (from.y.min(to.y), from.y.max(to.y));
+        if (min_x..=max_x).contains(&tile.x) && (min_y..=max_y).contains(&tile.y) {
+            return true;
+        }
+        // count the vertical edges to the right, each including its lower end only
+        if from.x == to.x && from.x > tile.x && (min_y..max_y).contains(&tile.y) {
+            crossings = crossings.saturating_add(1);
+        }
+    }
+    !crossings.is_multiple_of(2)
+}
+
+/// Finds the largest rectangle inside the loop by checking every tile of every rectangle
+fn brute_force(tiles: &[Position2D]) -> u64 {
+    let mut best = 0;
+    for first in tiles {
+        for second in tiles {
+            let (min_x, max_x) = (first.x.min(second.x), first.x.max(second.x));
+            let (min_y, max_y) = (first.y.min(second.y), first.y.max(second.y));
+            let inside = (min_x..=max_x)
+                .all(|x| (min_y..=max_y).all(|y| is_inside(tiles, Position2D::new(x, y))));
+            if inside {
+                best = best.max(rectangle_area(first, second).unwrap_or(0));
+            }
+        }
+    }
+    best
+}
+
+/// Checks the containment test and the largest rectangle against checking every tile
+#[test]
+fn matches_brute_force() {
+    for content in LOOPS {
+        let tiles = parse_input(content).unwrap_or_default();
+        let interior = Interior::new(&tiles).expect("The loop is valid.");
+        for first in &tiles {
+            for second in &tiles {
+                let (min_x, max_x) = (first.x.min(second.x), first.x.max(second.x));
+                let (min_y, max_y) = (first.y.min(second.y), first.y.max(second.y));
+                let expected = (min_x..=max_x)
+                    .all(|x| (min_y..=max_y).all(|y| is_inside(&tiles, Position2D::new(x, y))));
+                assert_eq!(
+                    interior.contains_rectangle(first, second),
+                    expected,
+                    "{first} - {second}"
+                );
+            }
+        }
+        assert_eq!(largest_enclosed_rectangle(&tiles), Ok(brute_force(&tiles)));
+    }
+}
+
+/// Checks that a notch between two adjacent walls does not connect the inside with the outside
+#[test]
+fn adjacent_walls_enclose_everything() {
+    let tiles = parse_input("0,0\n10,0\n10,10\n4,10\n4,2\n3,2\n3,10\n0,10").unwrap_or_default();
+    let interior = Interior::new(&tiles).expect("The loop is valid.");
+    assert!(interior.contains_rectangle(&Position2D::new(0, 0), &Position2D::new(10, 10)));
+    assert_eq!(largest_enclosed_rectangle(&tiles), Ok(121));
+}
+
+/// Checks that consecutive red tiles must share a row or a column and that corners must be on
+/// the rows and columns of red tiles
+#[test]
+fn rejects_invalid_tiles() {
+    let tiles = parse_input("0,0\n3,0\n5,4").unwrap_or_default();
+    assert_eq!(
+        Interior::new(&tiles),
+        Err("The red tiles 3,0 and 5,4 are not in the same row or column!".to_owned())
+    );
+    let outside = Interior::new(&parse_input("0,0\n2,0\n2,2\n0,2").unwrap_or_default());
+    assert!(outside.is_ok_and(|interior| {
+        !interior.contains_rectangle(&Position2D::new(0, 0), &Position2D::new(3, 2))
+    }));
+}
@@ -0,0 +1,60 @@
+//! Tests for the fast largest rectangle against examining all pairs of red tiles
+
+use core::iter;
+
+use aoc_common::test_util::Lcg;
+use day09::{Position2D, largest_rectangle, largest_rectangle_brute_force, parse_input};
+
+/// Generates pseudo-random tiles around the origin, with many shared rows and columns if `spread`
+/// is small
+fn random_tiles(seed: u64, count: usize, spread: u64) -> Vec<Position2D> {
+    let mut rng = Lcg::new(seed);
+    let mut next = || {
+        let value = i64::try_from(rng.below(spread)).unwrap_or(0);
+        value.saturating_sub(i64::try_from(spread >> 1_u32).unwrap_or(0))
+    };
+    let lines = iter::repeat_with(|| format!("{},{}", next(), next()))
+        .take(count)
+        .collect::<Vec<String>>();
+    parse_input(&lines.join("\n")).unwrap_or_default()
+}
+
+/// Checks the staircase search against all pairs on many small inputs
+#[test]
+fn matches_brute_force() {
+    for seed in 0..300 {
+        for (count, spread) in [(2, 3), (5, 4), (12, 10), (40, 25), (200, 1000)] {
+            let tiles = random_tiles(seed, count, spread);
+            assert_eq!(
+                largest_rectangle(&tiles),
+                largest_rectangle_brute_force(&tiles),
+                "seed {seed} with {count} tiles"
+            );
+        }
+    }
+}
+
+/// Checks inputs without a second distinct tile and tiles on a single line
+#[test]
+fn degenerate_inputs() {
+    for content in ["", "3,4", "3,4\n3,4", "1,5\n1,-5\n1,0", "-2,7\n9,7\n4,7"] {
+        let tiles = parse_input(content).unwrap_or_default();
+        assert_eq!(
+            largest_rectangle(&tiles),
+            largest_rectangle_brute_force(&tiles),
+            "{content}"
+        );
+    }
+}
+
+/// Checks a worst case with 10⁵ tiles on a diagonal line, which are all on both staircases
+#[test]
+fn many_tiles_on_staircases() {
+    let count = 100_000_i64;
+    let lines = (0..count)
+        .map(|idx| format!("{idx},{}", count.saturating_sub(idx)))
+        .collect::<Vec<String>>();
+    let tiles = parse_input(&lines.join("\n")).unwrap_or_default();
+    let side = u64::try_from(count).unwrap_or(0);
+    assert_eq!(largest_rectangle(&tiles), Ok(side.saturating_mul(side)));
+}
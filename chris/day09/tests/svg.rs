@@ -0,0 +1,28 @@
+//! Tests for the SVG drawing of the tiles
+
+use day09::{best_enclosed_rectangle, best_rectangle, parse_input, svg};
+
+/// Checks that the sample is scaled to the canvas and both rectangles are labeled
+#[test]
+fn draws_sample() {
+    let tiles = parse_input("7,1\n11,1\n11,7\n9,7\n9,5\n2,5\n2,3\n7,3").unwrap_or_default();
+    let largest = best_rectangle(&tiles).unwrap_or_default();
+    let enclosed = best_enclosed_rectangle(&tiles).unwrap_or_default();
+    let drawing = svg::render(&tiles, largest.as_ref(), enclosed.as_ref());
+    assert!(drawing.starts_with("<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"880\""));
+    assert!(drawing.contains("<polygon points=\"480,80 800,80 800,560 640,560 "));
+    assert!(drawing.contains("<rect x=\"40\" y=\"40\" width=\"800\" height=\"400\""));
+    assert!(drawing.contains(">Part 1: 2,5 and 11,1, area 50</text>"));
+    assert!(drawing.contains(">Part 2: 9,5 and 2,3, area 24</text>"));
+    assert_eq!(drawing.matches("<circle ").count(), tiles.len());
+    assert!(drawing.ends_with("</svg>\n"));
+}
+
+/// Checks that huge coordinates are scaled down and that missing rectangles are left out
+#[test]
+fn scales_large_coordinates() {
+    let tiles = parse_input("-100000,0\n100000,0\n100000,50000\n-100000,50000").unwrap_or_default();
+    let drawing = svg::render(&tiles, None, None);
+    assert!(drawing.contains("<polygon points=\"40,40 839,40 839,239 40,239\""));
+    assert!(!drawing.contains("<text"));
+}
@@ -0,0 +1,178 @@
+//! Systems of linear equations over GF(2), the field with the elements 0 and 1
+//!
+//! Pressing a button twice has no effect and the order of the presses does not matter, so every
+//! button is pressed either once or not at all. Each light gives an equation: the number of
+//! pressed buttons that toggle it must be odd exactly if the light should be on. Addition is XOR
+//! in this field, so the unknowns and the coefficients of an equation fit into the bits of a
+//! `u64`.
+
+use log::debug;
+
+/// Largest number of equations and of unknowns of a system, which are the bits of a `u64`
+#[expect(
+    clippy::decimal_literal_representation,
+    reason = "The number of bits is more readable in decimal notation."
+)]
+pub const MAX_SIZE: usize = 64;
+
+/// Bits of the unknowns that occur in an equation and whether their sum must be 1
+type Equation = (u64, bool);
+
+/// Equation with the unknown that leads it
+type Pivot = (usize, Equation);
+
+/// System of linear equations over GF(2) in reduced row echelon form
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LinearSystem {
+    /// Unknowns that do not lead any equation and can be chosen freely
+    free: Vec<usize>,
+    /// Equations that are not trivially fulfilled, each with its leading unknown
+    pivots: Vec<Pivot>,
+    /// Whether an equation reduced to `0 = 1`
+    unsolvable: bool,
+}
+
+impl LinearSystem {
+    /// Chooses the free unknowns from `depth` on, after those before were set as in `chosen`,
+    /// and keeps the best solution found in `best`
+    ///
+    /// `complete_at` lists for each depth the pivot equations whose free unknowns have all been
+    /// chosen by then. A branch ends as soon as it has more ones than the best solution.
+    fn branch(
+        &self,
+        depth: usize,
+        chosen: u64,
+        complete_at: &[Vec<Pivot>],
+        best: &mut Option<u64>,
+    ) {
+        let leading_bits = complete_at
+            .get(depth)
+            .into_iter()
+            .flatten()
+            .filter(|&&(_, (coefficients, sum))| {
+                let odd_free = !(coefficients & chosen).count_ones().is_multiple_of(2);
+                sum != odd_free
+            })
+            .filter_map(|&(unknown, _)| bit(unknown))
+            .fold(0, |acc, unknown_bit| acc | unknown_bit);
+        let bits = chosen | leading_bits;
+        if best.is_some_and(|solution| bits.count_ones() > solution.count_ones()) {
+            return;
+        }
+        let Some(&unknown) = self.free.get(depth) else {
+            if best.is_none_or(|solution| {
+                (bits.count_ones(), bits) < (solution.count_ones(), solution)
+            }) {
+                *best = Some(bits);
+            }
+            return;
+        };
+        let next = depth.saturating_add(1);
+        self.branch(next, bits, complete_at, best);
+        if let Some(unknown_bit) = bit(unknown) {
+            self.branch(next, bits | unknown_bit, complete_at, best);
+        }
+    }
+
+    /// Finds the solution with the fewest unknowns set to 1
+    ///
+    /// Returns the bits of the unknowns that are 1, or `None` if the system has no solution.
+    /// Solutions with the same number of ones are ordered by their bits.
+    ///
+    /// The free unknowns are chosen one after another, and every pivot equation sets its leading
+    /// unknown as soon as all of its free unknowns are chosen. Branches that already have more
+    /// ones than the best solution so far are skipped, so there is no limit on the number of
+    /// free unknowns, but systems with many of them and heavy solutions take long.
+    #[must_use]
+    pub fn minimum_weight_solution(&self) -> Option<u64> {
+        if self.unsolvable {
+            return None;
+        }
+        let mut complete_at = vec![Vec::new(); self.free.len().saturating_add(1)];
+        for &(unknown, equation) in &self.pivots {
+            let depth = self
+                .free
+                .iter()
+                .rposition(|&free| bit(free).is_some_and(|free_bit| equation.0 & free_bit != 0))
+                .map_or(0, |idx| idx.saturating_add(1));
+            if let Some(pivots) = complete_at.get_mut(depth) {
+                pivots.push((unknown, equation));
+            }
+        }
+        let mut best = None;
+        self.branch(0, 0, &complete_at, &mut best);
+        debug!(
+            "Chose the free unknowns {:?} for the solution {best:?}.",
+            self.free
+        );
+        best
+    }
+
+    /// Brings the equations into reduced row echelon form by Gaussian elimination
+    ///
+    /// Each of the `columns` holds the bits of the equations in which one unknown occurs, and
+    /// `target` holds the bits of the equations whose sum must be 1.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if there are more than [`MAX_SIZE`] unknowns.
+    pub fn new(columns: &[u64], target: u64) -> Result<Self, String> {
+        if columns.len() > MAX_SIZE {
+            return Err(format!(
+                "A system can have at most {MAX_SIZE} unknowns, but {} were given!",
+                columns.len()
+            ));
+        }
+        let mut equations = (0..MAX_SIZE)
+            .filter_map(|row| {
+                let row_bit = bit(row)?;
+                let coefficients = columns
+                    .iter()
+                    .enumerate()
+                    .filter(|&(_, column)| column & row_bit != 0)
+                    .filter_map(|(unknown, _)| bit(unknown))
+                    .fold(0, |acc, unknown_bit| acc | unknown_bit);
+                Some((coefficients, target & row_bit != 0))
+            })
+            .collect::<Vec<Equation>>();
+        let mut pivots = Vec::new();
+        let mut free = Vec::new();
+        for unknown in 0..columns.len() {
+            let Some(unknown_bit) = bit(unknown) else {
+                continue;
+            };
+            let Some(leading) = equations
+                .iter()
+                .position(|&(coefficients, _)| coefficients & unknown_bit != 0)
+            else {
+                free.push(unknown);
+                continue;
+            };
+            let pivot = equations.swap_remove(leading);
+            // eliminate the unknown from all other equations, including the earlier pivots
+            let others = equations
+                .iter_mut()
+                .chain(pivots.iter_mut().map(|&mut (_, ref mut equation)| equation));
+            for equation in others {
+                if equation.0 & unknown_bit != 0 {
+                    equation.0 ^= pivot.0;
+                    equation.1 ^= pivot.1;
+                }
+            }
+            pivots.push((unknown, pivot));
+        }
+        // all remaining equations have no unknowns left
+        let unsolvable = equations.iter().any(|&(_, sum)| sum);
+        Ok(Self {
+            free,
+            pivots,
+            unsolvable,
+        })
+    }
+}
+
+/// Mask with only the bit of the given index set, or `None` if the index is too large
+#[must_use]
+pub fn bit(index: usize) -> Option<u64> {
+    1_u64.checked_shl(u32::try_from(index).ok()?)
+}
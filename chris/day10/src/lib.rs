@@ -0,0 +1,210 @@
+//! Solves day 10 of Advent of Code 2025
+
+pub mod gf2;
+
+use core::str::FromStr;
+
+use aoc_common::input::{ParseError, parse_token};
+use aoc_common::solution::{Part, Solution};
+use gf2::{LinearSystem, MAX_SIZE, bit};
+use log::{debug, info};
+use regex::Regex;
+
+/// Represents a button wiring, defining which indicator lights are toggled by a button
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ButtonWiring {
+    /// Bits of the indicator lights (0-based) that are toggled by this button
+    toggled_lights: u64,
+}
+
+/// Represents a full factory machine with indicator lights, required joltage and buttons
+#[derive(Debug)]
+pub struct FactoryMachine {
+    /// The button wirings of the machine
+    buttons: Vec<ButtonWiring>,
+    /// Number of indicator lights of the machine
+    light_count: usize,
+    /// The required joltages of the machine
+    #[expect(dead_code, reason = "Only needed for part 2, which is not solved yet.")]
+    required_joltage: Vec<u32>,
+    /// Bits of the indicator lights that should be on, all lights are off at the start
+    target_lights: u64,
+}
+
+impl ButtonWiring {
+    /// Parse a `ButtonWiring` from a string representation (e.g. `"(17, 8)"`)
+    /// that is part of the line `line` with index `line_idx`
+    ///
+    /// All indices must be below `light_count`.
+    fn from_str(
+        line_idx: usize,
+        line: &str,
+        text: &str,
+        light_count: usize,
+    ) -> Result<Vec<Self>, ParseError> {
+        let Ok(pattern) = Regex::new(r"\(((?:\d,?\s*)+)\)") else {
+            return Err(ParseError::in_input(
+                "a valid pattern for button wirings (internal error, this should never happen!)",
+            ));
+        };
+        pattern
+            .captures_iter(text)
+            .map(|button_capture| {
+                let button_str = button_capture
+                    .get(1)
+                    .ok_or_else(|| {
+                        ParseError::at_token(line_idx, line, text, "a button wiring like `(1,3)`")
+                    })?
+                    .as_str();
+                let toggled_lights = parse_list::<usize>(line_idx, line, button_str)?
+                    .into_iter()
+                    .map(|light| bit(light).filter(|_| light < light_count))
+                    .try_fold(0, |acc, light_bit| Some(acc | light_bit?))
+                    .ok_or_else(|| {
+                        ParseError::at_token(
+                            line_idx,
+                            line,
+                            button_str,
+                            &format!("indices of the {light_count} indicator lights"),
+                        )
+                    })?;
+                Ok(Self { toggled_lights })
+            })
+            .collect::<Result<Vec<Self>, ParseError>>()
+    }
+}
+
+impl FactoryMachine {
+    /// Indices of the buttons that configure the indicator lights with the fewest presses
+    ///
+    /// Every button is pressed at most once, since pressing it twice has no effect.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if no combination of buttons configures the lights.
+    pub fn fewest_button_presses(&self) -> Result<Vec<usize>, String> {
+        let columns = self
+            .buttons
+            .iter()
+            .map(|button| button.toggled_lights)
+            .collect::<Vec<u64>>();
+        let pressed = LinearSystem::new(&columns, self.target_lights)?
+            .minimum_weight_solution()
+            .ok_or_else(|| {
+                let lights = (0..self.light_count)
+                    .map(|light| {
+                        let is_on =
+                            bit(light).is_some_and(|light_bit| self.target_lights & light_bit != 0);
+                        if is_on { '#' } else { '.' }
+                    })
+                    .collect::<String>();
+                format!("No combination of buttons configures the indicator lights [{lights}]!")
+            })?;
+        let buttons = (0..self.buttons.len())
+            .filter(|&idx| bit(idx).is_some_and(|idx_bit| pressed & idx_bit != 0))
+            .collect::<Vec<usize>>();
+        debug!("Pressing the buttons {buttons:?}.");
+        Ok(buttons)
+    }
+}
+
+/// Solution for day 10
+#[derive(Debug)]
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+    const PARTS: &'static [Part] = &[Part::One];
+
+    type Input = Vec<FactoryMachine>;
+
+    fn parse_input(content: &str) -> Result<Self::Input, ParseError> {
+        parse_input(content)
+    }
+
+    fn part1(input: &Self::Input) -> Result<String, String> {
+        Ok(sum_fewest_button_presses(input)?.to_string())
+    }
+}
+
+/// Parses a list of comma-separated numbers in `text`, which is part of the line `line`
+fn parse_list<T: FromStr>(line_idx: usize, line: &str, text: &str) -> Result<Vec<T>, ParseError> {
+    text.split(',')
+        .map(|num_str| parse_token::<T>(line_idx, line, num_str.trim(), "a number"))
+        .collect::<Result<Vec<T>, ParseError>>()
+}
+
+/// Parses input for day 10 (e.g. `"[.##.] (3) (1,3) (2) (2,3) (0,2) (0,1) {3,5,4,7}"`)
+///
+/// # Errors
+///
+/// Returns an error if a line does not match the expected format.
+pub fn parse_input(content: &str) -> Result<Vec<FactoryMachine>, ParseError> {
+    let Ok(pattern) = Regex::new(r"\[([.#]+)\] ((?:\((?:\d+,?\s*)+\)\s*)+) \{((?:\d+,?\s*)+)\}")
+    else {
+        return Err(ParseError::in_input(
+            "a valid pattern for machines (internal error, this should never happen!)",
+        ));
+    };
+    content
+        .lines()
+        .enumerate()
+        .map(|(idx, line)| {
+            let Some(cap) = pattern.captures(line) else {
+                return Err(ParseError::at_line(
+                    idx,
+                    line,
+                    "a machine like `[.##.] (3) (1,3) {3,5,4,7}`",
+                ));
+            };
+            let (_, [indicators_str, buttons_str, joltages_str]) = cap.extract();
+            let light_count = indicators_str.len();
+            if light_count > MAX_SIZE {
+                return Err(ParseError::at_token(
+                    idx,
+                    line,
+                    indicators_str,
+                    &format!("at most {MAX_SIZE} indicator lights"),
+                ));
+            }
+            let buttons = ButtonWiring::from_str(idx, line, buttons_str, light_count)?;
+            if buttons.len() > MAX_SIZE {
+                return Err(ParseError::at_token(
+                    idx,
+                    line,
+                    buttons_str,
+                    &format!("at most {MAX_SIZE} buttons"),
+                ));
+            }
+            let target_lights = indicators_str
+                .chars()
+                .enumerate()
+                .filter(|&(_, chr)| chr == '#')
+                .filter_map(|(light, _)| bit(light))
+                .fold(0, |acc, light_bit| acc | light_bit);
+            Ok(FactoryMachine {
+                buttons,
+                light_count,
+                required_joltage: parse_list(idx, line, joltages_str)?,
+                target_lights,
+            })
+        })
+        .collect::<Result<Vec<FactoryMachine>, ParseError>>()
+}
+
+/// Solves part 1
+///
+/// # Errors
+///
+/// Returns an error message if no solution is found for any of the machines.
+pub fn sum_fewest_button_presses(machines: &[FactoryMachine]) -> Result<usize, String> {
+    let presses = machines
+        .iter()
+        .map(|machine| Ok(machine.fewest_button_presses()?.len()))
+        .sum::<Result<usize, String>>()?;
+    info!(
+        "Configured {} machines with {presses} button presses.",
+        machines.len()
+    );
+    Ok(presses)
+}
@@ -0,0 +1,110 @@
+//! Tests for solving the indicator lights as linear equations over GF(2)
+
+use core::iter;
+
+use aoc_common::test_util::Lcg;
+use day10::gf2::LinearSystem;
+use day10::{FactoryMachine, parse_input};
+
+/// Finds the solution with the fewest ones by trying all combinations of the unknowns
+fn brute_force(columns: &[u64], target: u64) -> Option<u64> {
+    let combinations = 1_u64.checked_shl(u32::try_from(columns.len()).unwrap_or(0))?;
+    (0..combinations)
+        .filter(|&choice| {
+            let lights = columns
+                .iter()
+                .enumerate()
+                .filter(|&(idx, _)| ((choice >> idx) & 1) == 1)
+                .fold(0, |acc, (_, &column)| acc ^ column);
+            lights == target
+        })
+        .min_by_key(|&choice| (choice.count_ones(), choice))
+}
+
+/// Checks the Gaussian elimination against all combinations on pseudo-random systems
+#[test]
+fn matches_brute_force() {
+    let mut rng = Lcg::new(2025);
+    let mut next = |bound: u64| rng.below(bound);
+    for _ in 0_u32..2000 {
+        let lights = next(10).saturating_add(1);
+        let buttons = usize::try_from(next(12)).unwrap_or(0);
+        let mask = (1_u64 << lights).saturating_sub(1);
+        let columns = iter::repeat_with(|| next(mask.saturating_add(1)))
+            .take(buttons)
+            .collect::<Vec<u64>>();
+        let target = next(mask.saturating_add(1));
+        let system = LinearSystem::new(&columns, target).expect("The system is small enough.");
+        assert_eq!(
+            system.minimum_weight_solution(),
+            brute_force(&columns, target),
+            "{columns:?} = {target}"
+        );
+    }
+}
+
+/// Checks systems with far more free unknowns than could be tried one combination at a time
+#[test]
+fn many_free_unknowns() {
+    // 40 buttons that each toggle one of 6 lights leave 34 free unknowns
+    let mut columns = (0_usize..40)
+        .map(|idx| 1_u64 << idx.rem_euclid(6))
+        .collect::<Vec<u64>>();
+    let three_lights = LinearSystem::new(&columns, 0b111).expect("The system is small enough.");
+    assert_eq!(three_lights.minimum_weight_solution(), Some(0b111));
+    let all_lights = LinearSystem::new(&columns, 0b11_1111).expect("The system is small enough.");
+    assert_eq!(all_lights.minimum_weight_solution(), Some(0b11_1111));
+
+    // a late button that toggles all lights at once beats six single ones
+    if let Some(column) = columns.get_mut(37) {
+        *column = 0b11_1111;
+    }
+    let one_button = LinearSystem::new(&columns, 0b11_1111).expect("The system is small enough.");
+    assert_eq!(one_button.minimum_weight_solution(), Some(1 << 37_u32));
+    let unsolvable = LinearSystem::new(&columns, 0b100_0000).expect("The system is small enough.");
+    assert_eq!(unsolvable.minimum_weight_solution(), None);
+}
+
+/// Checks the buttons that are pressed for the machines of the sample
+#[test]
+fn sample_buttons() {
+    let machines = parse_input(
+        "[.##.] (3) (1,3) (2) (2,3) (0,2) (0,1) {3,5,4,7}\n\
+         [...#.] (0,2,3,4) (2,3) (0,4) (0,1,2) (1,2,3,4) {7,5,12,7,2}\n\
+         [.###.#] (0,1,2,3,4) (0,3,4) (0,1,2,4,5) (1,2) {10,11,11,5,10,5}\n\
+         [....] (0,1) {1,2}",
+    )
+    .unwrap_or_default();
+    let presses = machines
+        .iter()
+        .map(FactoryMachine::fewest_button_presses)
+        .collect::<Vec<Result<Vec<usize>, String>>>();
+    assert_eq!(
+        presses,
+        vec![
+            Ok(vec![1, 3]),
+            Ok(vec![2, 3, 4]),
+            Ok(vec![1, 2]),
+            Ok(vec![])
+        ]
+    );
+}
+
+/// Checks that unreachable light patterns and invalid buttons are reported
+#[test]
+fn reports_errors() {
+    let machines = parse_input("[##.] (0) (0,1) (0,1,2) (2) {1}\n[#.] (1) {1}").unwrap_or_default();
+    assert!(
+        machines
+            .first()
+            .is_some_and(|machine| machine.fewest_button_presses().is_ok())
+    );
+    assert!(
+        machines
+            .get(1)
+            .is_some_and(|machine| machine.fewest_button_presses().is_err())
+    );
+    let err = parse_input("[#.] (0,2) {1}").expect_err("Light 2 does not exist.");
+    assert_eq!(err.expected(), "indices of the 2 indicator lights");
+    assert_eq!(err.columns(), Some(6..9));
+}
@@ -0,0 +1,319 @@
+//! Solves day 11 of Advent of Code 2025
+use aoc_common::graph::{ExportFormat, Graph, Highlight, NodeId, NodeStyle, Weight, WeightedPath};
+use aoc_common::input::{ParseError, parse_token};
+use aoc_common::solution::Solution;
+use log::{debug, info, warn};
+
+/// Name of the node where part 1 starts
+pub const SOURCE: &str = "you";
+
+/// Name of the node where all paths end
+pub const SINK: &str = "out";
+
+/// Solution for day 11
+#[derive(Debug)]
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+
+    type Input = Graph;
+
+    fn parse_input(content: &str) -> Result<Self::Input, ParseError> {
+        parse_input(content)
+    }
+
+    fn part1(input: &Self::Input) -> Result<String, String> {
+        Ok(count_paths_from_source_to_sink(input)?.to_string())
+    }
+
+    fn part2(input: &Self::Input) -> Result<String, String> {
+        Ok(count_paths_to_sink("svr", &["dac", "fft"], SINK, input)?.to_string())
+    }
+}
+
+/// Parse input for day 11
+///
+/// # Errors
+///
+/// Returns an error if a line does not contain a colon or an edge has an invalid weight.
+/// Outputs can have a weight in the form `child:weight`, all other edges have weight 1.
+/// Cycles are no error, but are logged as warning because only simple paths can be counted then.
+pub fn parse_input(content: &str) -> Result<Graph, ParseError> {
+    let mut grph = Graph::new();
+    for (idx, line) in content.lines().enumerate() {
+        let Some((name, children)) = line.split_once(':') else {
+            return Err(ParseError::at_line(
+                idx,
+                line,
+                "a device and its outputs (e.g. `aaa: bbb ccc`)",
+            ));
+        };
+        debug!("Adding node {name:?} with outgoing {children:?}");
+        let node = grph.insert(name.trim());
+        for token in children.split_ascii_whitespace() {
+            let (child, weight) = match token.split_once(':') {
+                Some((child, weight)) => (
+                    child,
+                    parse_token::<Weight>(idx, line, weight, "an edge weight")?,
+                ),
+                None => (token, 1),
+            };
+            let child_node = grph.insert(child);
+            grph.add_weighted_edge(node, child_node, weight);
+        }
+    }
+    let cycles = grph.cycles();
+    if !cycles.is_empty() {
+        warn!(
+            "Found {} cycles, only simple paths can be counted! Nodes on cycles: {}",
+            cycles.len(),
+            grph.describe_cycles()
+        );
+    }
+    Ok(grph)
+}
+
+/// Finds all paths from source to sink
+///
+/// # Errors
+///
+/// Returns an error message if the graph has no source or no sink.
+///
+/// # Examples
+///
+/// ```
+/// use day11::{count_paths_from_source_to_sink, parse_input};
+///
+/// let graph = parse_input("you: aaa bbb\naaa: out\nbbb: aaa out").map_err(|err| err.to_string())?;
+/// assert_eq!(count_paths_from_source_to_sink(&graph)?, 3);
+/// # Ok::<(), String>(())
+/// ```
+pub fn count_paths_from_source_to_sink(grph: &Graph) -> Result<u128, String> {
+    if grph.id(SOURCE).is_none() {
+        return Err("Cannot find source!".to_owned());
+    }
+    if grph.id(SINK).is_none() {
+        return Err("Cannot find sink!".to_owned());
+    }
+    count_paths_to_sink(SOURCE, &[], SINK, grph)
+}
+
+/// Counts all paths from the `start_node` to the `end_node` that visit all nodes in `must_visit`.
+///
+/// # Errors
+///
+/// Returns an error message if one of the nodes does not exist, the graph contains a cycle
+/// or the number of paths is too large.
+///
+/// # Examples
+///
+/// ```
+/// use day11::{count_paths_to_sink, parse_input};
+///
+/// let graph = parse_input("svr: aaa bbb\naaa: fft\nbbb: fft\nfft: dac out\ndac: out").map_err(|err| err.to_string())?;
+/// assert_eq!(count_paths_to_sink("svr", &["dac", "fft"], "out", &graph)?, 2);
+/// # Ok::<(), String>(())
+/// ```
+pub fn count_paths_to_sink(
+    start_node: &str,
+    must_visit: &[&str],
+    end_node: &str,
+    grph: &Graph,
+) -> Result<u128, String> {
+    let start = node_id(grph, start_node)?;
+    let end = node_id(grph, end_node)?;
+    let waypoints = node_ids(grph, must_visit)?;
+    let paths = grph.count_paths(start, end, &waypoints)?;
+    info!("Found {paths} paths from {start_node} to {end_node} via {must_visit:?}");
+    Ok(paths)
+}
+
+/// Counts the simple paths from the `start_node` to the `end_node` that visit all nodes
+/// in `must_visit`, which also works if the graph contains cycles.
+///
+/// # Errors
+///
+/// Returns an error message if one of the nodes does not exist or the number of paths is too large.
+///
+/// # Examples
+///
+/// ```
+/// use day11::{count_simple_paths_to_sink, parse_input};
+///
+/// let graph = parse_input("you: aaa\naaa: bbb out\nbbb: aaa out").map_err(|err| err.to_string())?;
+/// assert_eq!(count_simple_paths_to_sink("you", &[], "out", &graph)?, 2);
+/// # Ok::<(), String>(())
+/// ```
+pub fn count_simple_paths_to_sink(
+    start_node: &str,
+    must_visit: &[&str],
+    end_node: &str,
+    grph: &Graph,
+) -> Result<u128, String> {
+    let start = node_id(grph, start_node)?;
+    let end = node_id(grph, end_node)?;
+    let waypoints = node_ids(grph, must_visit)?;
+    let paths = grph.count_simple_paths(start, end, &waypoints)?;
+    info!("Found {paths} simple paths from {start_node} to {end_node} via {must_visit:?}");
+    Ok(paths)
+}
+
+/// Renders the graph as DOT or Mermaid, highlighting the start, the end and the waypoints
+///
+/// With `annotate_paths`, each node shows the number of paths from the `start_node` to the
+/// `end_node` via all nodes in `must_visit` that pass through it.
+///
+/// # Errors
+///
+/// Returns an error message if one of the nodes does not exist or the paths cannot be counted.
+pub fn export_graph(
+    grph: &Graph,
+    format: ExportFormat,
+    start_node: &str,
+    must_visit: &[&str],
+    end_node: &str,
+    annotate_paths: bool,
+) -> Result<String, String> {
+    let start = node_id(grph, start_node)?;
+    let end = node_id(grph, end_node)?;
+    let waypoints = node_ids(grph, must_visit)?;
+    let paths_through = if annotate_paths {
+        grph.count_paths_through(start, end, &waypoints)?
+    } else {
+        Vec::new()
+    };
+    Ok(grph.export(format, |node| {
+        let highlight = if node == start {
+            Some(Highlight::Start)
+        } else if node == end {
+            Some(Highlight::End)
+        } else if waypoints.contains(&node) {
+            Some(Highlight::Waypoint)
+        } else {
+            None
+        };
+        let note = paths_through
+            .get(node)
+            .map(|paths| format!("{paths} paths"));
+        NodeStyle { highlight, note }
+    }))
+}
+
+/// Formats a path as its length and the names of its devices
+#[must_use]
+pub fn describe_path(grph: &Graph, path: &WeightedPath) -> String {
+    let names = path
+        .nodes
+        .iter()
+        .map(|&node| grph.name(node).unwrap_or("?"))
+        .collect::<Vec<&str>>();
+    format!("{} (length {})", names.join(" -> "), path.length)
+}
+
+/// Finds up to `count` shortest paths without repeated devices from the `start_node` to the
+/// `end_node`, ordered by their total weight
+///
+/// # Errors
+///
+/// Returns an error message if one of the nodes does not exist.
+pub fn k_shortest_paths(
+    start_node: &str,
+    end_node: &str,
+    count: usize,
+    grph: &Graph,
+) -> Result<Vec<WeightedPath>, String> {
+    let paths = grph.k_shortest_paths(node_id(grph, start_node)?, node_id(grph, end_node)?, count);
+    info!(
+        "Found {} of {count} shortest paths from {start_node} to {end_node}",
+        paths.len()
+    );
+    Ok(paths)
+}
+
+/// Lazily lists the paths without repeated devices from the `start_node` to the `end_node`
+/// that visit all nodes in `must_visit`, each as the names of its devices
+///
+/// Only the current path is kept in memory, so taking the first few paths is cheap even if
+/// there are millions of them.
+///
+/// # Errors
+///
+/// Returns an error message if one of the nodes does not exist or there are too many waypoints.
+///
+/// # Examples
+///
+/// ```
+/// use day11::{list_paths, parse_input};
+///
+/// let graph = parse_input("you: aaa bbb\naaa: out\nbbb: aaa out").map_err(|err| err.to_string())?;
+/// let paths = list_paths("you", &["bbb"], "out", &graph)?.collect::<Vec<_>>();
+/// assert_eq!(paths, vec![vec!["you", "bbb", "aaa", "out"], vec!["you", "bbb", "out"]]);
+/// # Ok::<(), String>(())
+/// ```
+pub fn list_paths<'graph>(
+    start_node: &str,
+    must_visit: &[&str],
+    end_node: &str,
+    grph: &'graph Graph,
+) -> Result<impl Iterator<Item = Vec<&'graph str>>, String> {
+    let start = node_id(grph, start_node)?;
+    let end = node_id(grph, end_node)?;
+    let waypoints = node_ids(grph, must_visit)?;
+    Ok(grph.simple_paths(start, end, &waypoints)?.map(|path| {
+        path.iter()
+            .map(|&node| grph.name(node).unwrap_or("?"))
+            .collect()
+    }))
+}
+
+/// Finds the path with the largest total weight from the `start_node` to the `end_node`
+///
+/// # Errors
+///
+/// Returns an error message if one of the nodes does not exist, there is no path,
+/// the graph contains a cycle or the length is too large.
+pub fn longest_path(
+    start_node: &str,
+    end_node: &str,
+    grph: &Graph,
+) -> Result<WeightedPath, String> {
+    grph.longest_path(node_id(grph, start_node)?, node_id(grph, end_node)?)?
+        .ok_or_else(|| format!("There is no path from {start_node} to {end_node}!"))
+}
+
+/// Looks up the node of a device
+fn node_id(grph: &Graph, name: &str) -> Result<NodeId, String> {
+    grph.id(name)
+        .ok_or_else(|| format!("Device {name} does not exist in the input!"))
+}
+
+/// Finds the path with the smallest total weight from the `start_node` to the `end_node`
+///
+/// # Errors
+///
+/// Returns an error message if one of the nodes does not exist or there is no path.
+///
+/// # Examples
+///
+/// ```
+/// use day11::{describe_path, parse_input, shortest_path};
+///
+/// let graph = parse_input("you: aaa:5 bbb\naaa: out\nbbb: aaa:1 out:9").map_err(|err| err.to_string())?;
+/// let path = shortest_path("you", "out", &graph)?;
+/// assert_eq!(describe_path(&graph, &path), "you -> bbb -> aaa -> out (length 3)");
+/// # Ok::<(), String>(())
+/// ```
+pub fn shortest_path(
+    start_node: &str,
+    end_node: &str,
+    grph: &Graph,
+) -> Result<WeightedPath, String> {
+    grph.shortest_path(node_id(grph, start_node)?, node_id(grph, end_node)?)
+        .ok_or_else(|| format!("There is no path from {start_node} to {end_node}!"))
+}
+
+/// Looks up the nodes of multiple devices
+fn node_ids(grph: &Graph, names: &[&str]) -> Result<Vec<NodeId>, String> {
+    names.iter().map(|name| node_id(grph, name)).collect()
+}
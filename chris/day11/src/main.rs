@@ -1,296 +1,156 @@
-//! Solves day 11 of Advent of Code 2025
-extern crate alloc;
-use alloc::borrow::ToOwned as _;
-use alloc::collections::VecDeque;
-use alloc::fmt;
-use alloc::rc::Rc;
-use core::cell::RefCell;
-use log::{debug, info};
-use std::collections::{HashMap, HashSet};
-use std::fs;
-use std::path::Path;
-use std::process::exit;
-
-/// Represents a node in the graph
-struct Node {
-    /// Incoming connections
-    incoming: Vec<Link>,
-    /// Name of the node
-    name: String,
-    /// Outgoing connections
-    outgoing: Vec<Link>,
-}
+//! Runs the solution for day 11 of Advent of Code 2025
 
-/// Link to a node
-pub(crate) type Link = Rc<RefCell<Node>>;
-
-impl Node {
-    /// Create a new node without any connections
-    const fn new(name: String) -> Self {
-        Self {
-            name,
-            incoming: Vec::new(),
-            outgoing: Vec::new(),
-        }
-    }
+use core::num::NonZeroUsize;
+use std::fs;
+use std::io::{self, Write as _};
+use std::path::PathBuf;
+
+use aoc_common::graph::{ExportFormat, Graph};
+use aoc_common::solution::run_cli_with;
+use clap::{Args, ValueEnum};
+use day11::{
+    Day11, SINK, SOURCE, count_paths_to_sink, count_simple_paths_to_sink, describe_path,
+    export_graph, k_shortest_paths, list_paths, longest_path, shortest_path,
+};
+
+/// Kind of path to search for with `--find-path`
+#[derive(Debug, Clone, Copy, ValueEnum)]
+enum PathKind {
+    /// Path with the largest total weight, which requires a graph without cycles
+    Longest,
+    /// Path with the smallest total weight
+    Shortest,
 }
 
-/// Represents a Graph
-#[derive(Debug)]
-struct Graph {
-    /// Hash map of all nodes by name
-    nodes: Rc<RefCell<HashMap<String, Link>>>,
-    /// Sink node
-    sink: Rc<RefCell<Option<Link>>>,
-    /// Source node
-    source: Rc<RefCell<Option<Link>>>,
+/// Arguments for examining the paths between any two devices instead of solving the puzzle
+#[derive(Debug, Args)]
+struct PathArgs {
+    /// Annotate each device in the export with the number of paths through it
+    #[arg(long, requires = "export")]
+    annotate_paths: bool,
+    /// Export the graph in the given format instead of counting paths
+    #[arg(long, value_enum)]
+    export: Option<ExportFormat>,
+    /// Show the path with the smallest or largest total weight
+    #[arg(long, value_enum, conflicts_with_all = ["via", "simple_paths"])]
+    find_path: Option<PathKind>,
+    /// Device where the paths start [default: you]
+    #[arg(long)]
+    from: Option<String>,
+    /// List up to K shortest paths without repeated devices, ordered by their total weight
+    #[arg(long, value_name = "K", conflicts_with_all = ["via", "simple_paths"])]
+    k_shortest: Option<NonZeroUsize>,
+    /// Print at most this many paths with `--list-paths`
+    #[arg(long, requires = "list_paths")]
+    limit: Option<usize>,
+    /// Print the paths that visit no device twice instead of counting them
+    #[arg(long)]
+    list_paths: bool,
+    /// File to write the export to instead of printing it
+    #[arg(long, requires = "export")]
+    output: Option<PathBuf>,
+    /// Count only paths that visit no device twice, which also works if the graph has cycles
+    #[arg(long)]
+    simple_paths: bool,
+    /// Device where the paths end [default: out]
+    #[arg(long)]
+    to: Option<String>,
+    /// Device that the paths must pass through, can be repeated
+    #[arg(long)]
+    via: Vec<String>,
 }
 
-impl fmt::Debug for Node {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
-        f.debug_struct("Node")
-            .field("name", &self.name)
-            .field(
-                "incoming",
-                &self
-                    .incoming
-                    .iter()
-                    .map(|node| node.borrow().name.clone())
-                    .collect::<Vec<String>>(),
-            )
-            .field(
-                "outgoing",
-                &self
-                    .outgoing
-                    .iter()
-                    .map(|node| node.borrow().name.clone())
-                    .collect::<Vec<String>>(),
-            )
-            .finish()
-    }
-}
-
-impl Graph {
-    /// Adds a new empty node to the graph
-    #[expect(
-        clippy::unwrap_used,
-        reason = "Panic cannot occur since we inserted the node right before referencing it."
-    )]
-    fn add_empty(&self, name: &str) -> Link {
-        let new_node = Rc::new(RefCell::new(Node::new(name.to_owned())));
-        self.nodes.borrow_mut().insert(name.to_owned(), new_node);
-        let node_ref = self.nodes.borrow().get(name).unwrap().to_owned();
-        match name {
-            "you" => {
-                info!("Found source node {name}!");
-                self.source.replace(Some(Rc::clone(&node_ref)));
-            }
-            "out" => {
-                info!("Found sink node {name}!");
-                self.sink.replace(Some(Rc::clone(&node_ref)));
-            }
-            _ => {}
-        }
-
-        node_ref
-    }
-
-    /// Adds a node with outgoing edges, updating both forward and backward references
-    fn add_node(&self, name: &str, outgoing: Vec<&str>) {
-        // NOTE: Due to problems with the borrow checker not allowing us to call
-        // get_mut_or_add twice, we need to
-        debug!("Adding node {name:?} with outgoing {outgoing:?}");
-        let new_node = self.get_or_add(name);
-        for node_str in outgoing {
-            let node = self.get_or_add(node_str);
-            Self::connect(&new_node, &node);
-        }
-        // match self.nodes.get(name) {
-        //     Some(existing) =>
-        // }
-    }
-    /// Connect two nodes in the graph
-    fn connect(parent: &Link, child: &Link) {
-        let parent_name = parent.borrow().name.clone();
-        let child_name: String = child.borrow().name.clone();
-        debug!("Connecting nodes: {parent_name} <-> {child_name}");
-        parent.borrow_mut().outgoing.push(Rc::clone(child));
-        child.borrow_mut().incoming.push(Rc::clone(parent));
-    }
-    /// Get an existing node or add a new empty one and return that if it doesn't exist yet.
-    #[expect(
-        clippy::unwrap_used,
-        reason = "Panic cannot happen since we check for existence and add before accessing the value."
-    )]
-    fn get_or_add(&self, name: &str) -> Link {
-        let exists = self.nodes.borrow().get(name).is_some();
-        if !exists {
-            self.add_empty(name);
-        }
-        self.nodes.borrow().get(name).unwrap().to_owned()
-    }
-
-    /// Create a new empty graph
-    fn new() -> Self {
-        Self {
-            nodes: Rc::new(RefCell::new(HashMap::new())),
-            sink: Rc::new(RefCell::new(None)),
-            source: Rc::new(RefCell::new(None)),
-        }
+/// Prints the header and then each path on its own line as soon as it is found
+///
+/// Returns the number of printed paths.
+fn print_paths<'graph>(
+    header: &str,
+    paths: impl Iterator<Item = Vec<&'graph str>>,
+) -> Result<usize, String> {
+    let write_error = |err: io::Error| format!("Could not print the paths!\nReason: {err}");
+    let mut stdout = io::stdout().lock();
+    writeln!(stdout, "{header}").map_err(write_error)?;
+    let mut count = 0_usize;
+    for path in paths {
+        writeln!(stdout, "{}", path.join(" -> ")).map_err(write_error)?;
+        count = count.saturating_add(1);
     }
+    Ok(count)
 }
 
-// fn debug_graph() {
-//     let grph = Graph::new();
-//     grph.add_empty("Mango");
-//     grph.add_node("you", vec!["Mango"]);
-//     debug!("Test graph: {grph:?}");
-// }
-
-/// Parse input for day 11
-fn parse_input(content: &str) -> Result<Graph, String> {
-    let grph = Graph::new();
-    for line in content.lines() {
-        let Some((name, children)) = line.split_once(':') else {
-            return Err(format!("Could not parse line {line}"));
+/// Runs the query requested by the path arguments, if any of them is given
+fn run_path_query(args: &PathArgs, graph: &Graph) -> Result<Option<String>, String> {
+    let from = args.from.as_deref().unwrap_or(SOURCE);
+    let to = args.to.as_deref().unwrap_or(SINK);
+    let via = args.via.iter().map(String::as_str).collect::<Vec<&str>>();
+    if let Some(format) = args.export {
+        let exported = export_graph(graph, format, from, &via, to, args.annotate_paths)?;
+        let Some(output) = args.output.as_ref() else {
+            return Ok(Some(exported));
         };
-        grph.add_node(
-            name.trim(),
-            children.split_ascii_whitespace().collect::<Vec<&str>>(),
-        );
+        let path = output.display();
+        fs::write(output, exported)
+            .map_err(|err| format!("Could not write the graph to {path}!\nReason: {err}"))?;
+        return Ok(Some(format!("Saved the graph to {path}")));
     }
-    Ok(grph)
-}
-
-/// Finds all nodes that can potentially reach the sink node
-#[expect(
-    unused,
-    reason = "This was just a test function to play with the Graph structure."
-)]
-fn find_nodes_reaching_sink(grph: &Graph) -> Result<HashSet<String>, String> {
-    let Some(sink) = grph.sink.borrow().clone() else {
-        return Err("Sink does not exist!".to_owned());
-    };
-    let mut todo: VecDeque<Link> = VecDeque::new();
-    let mut can_reach_sink: HashSet<String> = HashSet::new();
-    todo.push_back(sink);
-    while !todo.is_empty() {
-        let Some(next) = todo.pop_front() else {
-            break;
-        };
-        can_reach_sink.insert(next.borrow().name.clone());
-        for incoming in next.borrow().incoming.clone() {
-            todo.push_back(incoming);
-        }
+    if let Some(result) = run_weighted_query(args, from, to, graph) {
+        return result.map(Some);
     }
-    Ok(can_reach_sink)
-}
-
-/// Finds all paths from source to sink
-#[expect(
-    unused,
-    reason = "This is the solution for part 1, which we keep for reference."
-)]
-fn count_paths_from_source_to_sink(grph: &Graph) -> Result<u32, String> {
-    let Some(source) = grph.source.borrow().clone() else {
-        return Err("Cannot find source!".to_owned());
+    let via_text = if via.is_empty() {
+        String::new()
+    } else {
+        format!(" via {}", via.join(", "))
     };
-    let Some(sink) = grph.sink.borrow().clone() else {
-        return Err("Cannot find source!".to_owned());
+    if args.list_paths {
+        let paths = list_paths(from, &via, to, graph)?.take(args.limit.unwrap_or(usize::MAX));
+        let count = print_paths(&format!("Paths from {from} to {to}{via_text}:"), paths)?;
+        return Ok(Some(format!("Listed {count} paths.")));
+    }
+    if args.from.is_none() && args.to.is_none() && args.via.is_empty() && !args.simple_paths {
+        return Ok(None);
+    }
+    let paths = if args.simple_paths {
+        count_simple_paths_to_sink(from, &via, to, graph)?
+    } else {
+        count_paths_to_sink(from, &via, to, graph)?
     };
-    count_paths_to_sink(
-        &source.borrow().name,
-        &Vec::new(),
-        &sink.borrow().name,
-        grph,
-    )
+    Ok(Some(format!(
+        "Paths from {from} to {to}{via_text}: {paths}"
+    )))
 }
 
-/// Find all paths from the `start_node` to the sink, considering only paths that contain all nodes in `must_visit`.
-fn count_paths_to_sink(
-    start_node: &str,
-    must_visit: &Vec<&str>,
-    end_node: &str,
-    grph: &Graph,
-) -> Result<u32, String> {
-    let Some(start_name) = grph
-        .nodes
-        .borrow()
-        .get(start_node)
-        .map(|x| x.borrow().name.clone())
-    else {
-        return Err("Source does not exist!".to_owned());
-    };
-    // (name of node, path to node)
-    let mut to_explore: VecDeque<(String, String)> = VecDeque::new();
-    let mut explored_paths: HashSet<String> = HashSet::new();
-    to_explore.push_back((start_name.clone(), start_name));
-    let mut paths_to_sink: HashSet<String> = HashSet::new();
-    let nodes = grph.nodes.borrow();
-    while !to_explore.is_empty() {
-        let Some((next_name, next_path)) = to_explore.pop_front() else {
-            continue;
+/// Runs the shortest, longest or k-shortest path query if one of them is requested
+fn run_weighted_query(
+    args: &PathArgs,
+    from: &str,
+    to: &str,
+    graph: &Graph,
+) -> Option<Result<String, String>> {
+    if let Some(kind) = args.find_path {
+        let (label, found) = match kind {
+            PathKind::Longest => ("Longest", longest_path(from, to, graph)),
+            PathKind::Shortest => ("Shortest", shortest_path(from, to, graph)),
         };
-        debug!("Exploring path {next_path}");
-        if next_name == end_node {
-            info!("Found new path to sink: {next_path}");
-            paths_to_sink.insert(next_path.clone());
-        }
-        if explored_paths.contains(&next_path) {
-            debug! {"Path already explored, skipping!"}
-            // don't visit the same path twice
-            continue;
-        }
-        explored_paths.insert(next_path.clone());
-        let Some(node) = nodes.get(&next_name) else {
-            continue;
-        };
-        for out_node in &node.borrow().outgoing {
-            let out_name = out_node.borrow().name.clone();
-            to_explore.push_front((out_name.clone(), format!("{next_path}->{out_name}")));
-        }
+        return Some(found.map(|path| {
+            format!(
+                "{label} path from {from} to {to}: {}",
+                describe_path(graph, &path)
+            )
+        }));
     }
-    u32::try_from(
-        paths_to_sink
-            .iter()
-            .filter(|path| must_visit.iter().all(|to_visit| path.contains(to_visit)))
-            .count(),
-    )
-    .map_err(|err| format!("Casting error: {err}"))
+    let count = args.k_shortest?.get();
+    Some(k_shortest_paths(from, to, count, graph).map(|paths| {
+        let mut lines = vec![format!(
+            "{} shortest paths from {from} to {to}:",
+            paths.len()
+        )];
+        lines.extend(paths.iter().enumerate().map(|(idx, path)| {
+            format!("{}. {}", idx.saturating_add(1), describe_path(graph, path))
+        }));
+        lines.join("\n")
+    }))
 }
 
-// /// Find all paths from the `start_node` to the sink, considering only paths that contain all nodes in `must_visit`.
-// fn list_paths_from_a_to_b(node_a: &str, node_b: &str)
-
-#[expect(
-    clippy::print_stdout,
-    clippy::print_stderr,
-    reason = "CLI function must report output."
-)]
 fn main() {
-    env_logger::init();
-    let input_path: &Path = Path::new("input.txt");
-    let contents: String = match fs::read_to_string(input_path) {
-        Ok(str) => str,
-        Err(err) => {
-            let input_disp = input_path.display();
-            eprintln!("Could not read {input_disp}!\nReason: Err({err})");
-            exit(1);
-        }
-    };
-    let Ok(input) = parse_input(&contents) else {
-        eprintln!("Could not parse input {contents}");
-        exit(1);
-    };
-    info!("Parsed input: {input:?}");
-    // let can_reach = find_nodes_reaching_sink(&input);
-    // info!("Nodes that can reach the sink: {can_reach:?}");
-    let count = match count_paths_to_sink("svr", &vec![], "dac", &input) {
-        Ok(cnt) => cnt,
-        Err(err) => {
-            eprintln!("Could not find count. Reason:\n{err}");
-            exit(1);
-        }
-    };
-    println!("Result: {count}");
+    run_cli_with::<Day11, PathArgs>("input.txt", run_path_query);
 }
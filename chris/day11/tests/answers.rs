@@ -0,0 +1,12 @@
+//! Regression tests for day 11 against the answers in `expected_answers.txt`
+
+use std::path::Path;
+
+use aoc_common::regression::check_expected_answers;
+use day11::Day11;
+
+/// Checks that the solution still produces all known answers
+#[test]
+fn expected_answers() -> Result<(), String> {
+    check_expected_answers::<Day11>(Path::new(env!("CARGO_MANIFEST_DIR")))
+}
@@ -0,0 +1,376 @@
+//! Solves day 12 of Advent of Code 2025
+
+use core::fmt::Debug;
+use core::iter::repeat;
+use std::collections::HashSet;
+
+use aoc_common::grid::Grid;
+use aoc_common::input::{ParseError, parse_token};
+use aoc_common::solution::{Part, Solution};
+use log::{Level, debug, error, info};
+use regex::Regex;
+
+/// State of a region
+pub type Pixels = Grid<bool>;
+
+/// Represents a present shape
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PresentShape {
+    /// The pixels occupied by the present
+    /// Rows are along the length, columns along the width
+    pub pixels: Pixels,
+}
+
+impl PresentShape {
+    /// Flips the shape along the length axis
+    fn flip_lengthwise(&self) -> Self {
+        Self {
+            pixels: self.pixels.flipped_vertically(),
+        }
+    }
+    /// Flips the shape along the width axis
+    fn flip_widthwise(&self) -> Self {
+        Self {
+            pixels: self.pixels.flipped_horizontally(),
+        }
+    }
+    /// Returns all different shapes that can be obtained by rotating and flipping this shape
+    fn orientations(&self) -> Vec<Self> {
+        let mut oris: HashSet<Self> = HashSet::new();
+        for flip in [
+            self.clone(),
+            self.flip_lengthwise(),
+            self.flip_widthwise(),
+            self.flip_lengthwise().flip_widthwise(),
+        ] {
+            for rot in [
+                flip.rotate_clockwise(),
+                flip.rotate_clockwise().rotate_clockwise(),
+                flip.rotate_clockwise()
+                    .rotate_clockwise()
+                    .rotate_clockwise(),
+            ] {
+                oris.insert(rot);
+            }
+        }
+        oris.into_iter().collect::<Vec<Self>>()
+    }
+
+    /// Rotates the shape clockwise (interpreting length as y-axis and width as x-axis)
+    fn rotate_clockwise(&self) -> Self {
+        Self {
+            pixels: self.pixels.rotated_clockwise(),
+        }
+    }
+}
+
+/// Represents a region under a tree and the requirements of presents that should be placed there
+#[derive(Debug)]
+pub struct TreeRegion {
+    /// Length of the region
+    length: usize,
+    /// The shapes of presents to be placed in the region
+    present_shapes: Vec<PresentShape>,
+    /// How many of the individual shapes should be placed in the region
+    shape_quantities: Vec<usize>,
+    /// Width of the region
+    width: usize,
+}
+
+impl TreeRegion {
+    /// Determines whether all presents of the desired shapes can fit into this region
+    fn fits_all(&self) -> bool {
+        if self.is_trivial_negative() {
+            info!("Found trivial negative!");
+            return false;
+        }
+        if self.is_trivial_positive() {
+            info!("Found trivial positive!");
+            return true;
+        }
+        let region = Pixels::filled(self.length, self.width, false);
+        let res =
+            self.fits_all_in_region(&region, self.shape_quantities.clone(), &mut HashSet::new());
+        if !res {
+            info!("Found region to be unsolvable after trying all combinations!");
+        }
+        res
+    }
+    /// Version of `can_fit()` that also accepts a current region for recursive calls.
+    fn fits_all_in_region(
+        &self,
+        current_region: &Pixels,
+        mut remaining_quantities: Vec<usize>,
+        known_impossible: &mut HashSet<Pixels>,
+    ) -> bool {
+        if known_impossible.contains(current_region) {
+            // We already know that there is no solution for this state
+            return false;
+        }
+        let Some((idx, _)) = remaining_quantities
+            .iter()
+            .enumerate()
+            .find(|&(_, quant)| *quant > 0)
+        else {
+            // We've placed all presents => We found a solution!
+            let current_region_str = print_region(current_region, None);
+            info!("Found a solution:\n{current_region_str}");
+            return true;
+        };
+        let Some(shape) = self.present_shapes.get(idx) else {
+            error!("Could not find shape with index {idx} in {self:?}. This should never happen!");
+            return false;
+        };
+        let total_remaining: usize = remaining_quantities.iter().sum();
+        if let Some(el) = remaining_quantities.get_mut(idx) {
+            *el = el.saturating_sub(1);
+        }
+        let orientations = shape.orientations();
+        // Iterate over all free positions and try placing the present there
+        let indices = (0..self.length).flat_map(|idxl| repeat(idxl).zip(0..self.width));
+        for (idx_length, idx_width) in indices {
+            for orientation in &orientations {
+                if !present_fits_in_region_at_pos(
+                    orientation,
+                    current_region,
+                    idx_length,
+                    idx_width,
+                ) {
+                    debug!(
+                        "Present of type {idx} does not fit into region at ({idx_length}, {idx_width}) with {total_remaining} remaining presents."
+                    );
+                    continue;
+                }
+                debug!(
+                    "Placing present of type {idx} at pos ({idx_length}, {idx_width}) with {total_remaining} remaining presents."
+                );
+                // If the present fits, copy the region, and place it there
+                let new_region = match place_present_in_region_at_pos(
+                    orientation,
+                    current_region,
+                    idx_length,
+                    idx_width,
+                ) {
+                    Ok(reg) => reg,
+                    Err(err) => {
+                        error!("Could not place present in region. Reason:\n{err}");
+                        return false;
+                    }
+                };
+                // Now check recursively if we reach a solution by placing the present there
+                if self.fits_all_in_region(
+                    &new_region,
+                    remaining_quantities.clone(),
+                    known_impossible,
+                ) {
+                    // If yes, we just return.
+                    return true;
+                }
+                // If no, we continue to evaluate different positions.
+                // But we first save our knowledge that this state did not lead to a solution
+                known_impossible.insert(new_region.clone());
+                // Also add variants
+                known_impossible.insert(new_region.flipped_vertically());
+                known_impossible.insert(new_region.flipped_horizontally());
+                known_impossible.insert(new_region.flipped_horizontally().flipped_vertically());
+            }
+        }
+        // We evaluated all positions but did not find a candidate => Unable to place.
+        debug!(
+            "Evaluated all positions for present of type {idx}, but found no free position with {total_remaining} remaining presents."
+        );
+        // Record impossibility of current state
+        known_impossible.insert(current_region.clone());
+        known_impossible.insert(current_region.flipped_vertically());
+        known_impossible.insert(current_region.flipped_horizontally());
+        known_impossible.insert(current_region.flipped_horizontally().flipped_vertically());
+        false
+    }
+    /// Checks whether this region is a trivial negative that can't fit all presents
+    /// This is the case if the number of spaces that would need to be filled by the presents
+    /// is strictly higher than the number of spaces available in the region.
+    fn is_trivial_negative(&self) -> bool {
+        let available = self.length.saturating_mul(self.width);
+        let required = self
+            .shape_quantities
+            .iter()
+            .zip(self.present_shapes.iter())
+            .map(|(count, shape)| {
+                count.saturating_mul(shape.pixels.values().filter(|&&pixel| pixel).count())
+            })
+            .sum();
+        available < required
+    }
+    /// Checks whether this region is a trivial positive that can fit all presents
+    /// This is the case if the presents can be fit into the region without interlocking any of
+    /// them within the area of another present.
+    fn is_trivial_positive(&self) -> bool {
+        let available = self.length.saturating_mul(self.width);
+        let required = self
+            .shape_quantities
+            .iter()
+            .zip(self.present_shapes.iter())
+            .map(|(count, shape)| {
+                count.saturating_mul(shape.pixels.rows().saturating_mul(shape.pixels.columns()))
+            })
+            .sum();
+        available >= required
+    }
+}
+
+/// Solution for day 12
+#[derive(Debug)]
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+    const PARTS: &'static [Part] = &[Part::One];
+
+    type Input = Vec<TreeRegion>;
+
+    fn parse_input(content: &str) -> Result<Self::Input, ParseError> {
+        parse_input(content)
+    }
+
+    fn part1(input: &Self::Input) -> Result<String, String> {
+        Ok(count_solvable(input).to_string())
+    }
+
+    fn part2(_input: &Self::Input) -> Result<String, String> {
+        Err("There is no part 2 for day 12.".to_owned())
+    }
+}
+
+/// Checks whether the present `present` fits into `region` at index (`idx_len`, `idx_width`)
+#[must_use]
+pub fn present_fits_in_region_at_pos(
+    present: &PresentShape,
+    region: &Pixels,
+    idx_len: usize,
+    idx_width: usize,
+) -> bool {
+    if log::log_enabled!(Level::Debug) {
+        let present_str = print_region(&present.pixels, None);
+        let region_str = print_region(region, Some((idx_len, idx_width)));
+        debug!(
+            "Checking whether package fits in region.\n\nPackage:\n{present_str}\n\nRegion:\n{region_str}"
+        );
+    }
+    for ((row, col), &present_pixel) in present.pixels.iter() {
+        let Some(&region_pixel) =
+            region.get(idx_len.saturating_add(row), idx_width.saturating_add(col))
+        else {
+            debug!("Could not get region pixel at ({row}, {col}) of the present!");
+            return false;
+        };
+        if present_pixel && region_pixel {
+            debug!(
+                "Pixel mismatch: present_pixel = {present_pixel}, region_pixel = {region_pixel}"
+            );
+            return false;
+        }
+    }
+    true
+}
+
+/// Prints a region in the same format as used by the exercise description
+fn print_region(pixels: &Pixels, mark_position: Option<(usize, usize)>) -> String {
+    let (mark_len, mark_wid) = mark_position.unwrap_or((usize::MAX, usize::MAX));
+    pixels
+        .iter_rows()
+        .enumerate()
+        .map(|(idx_len, len_slice)| {
+            let mut len_slice_str = len_slice
+                .iter()
+                .enumerate()
+                .map(|(idx_wid, pix)| {
+                    if idx_len == mark_len && idx_wid == mark_wid {
+                        if *pix { 'X' } else { 'x' }
+                    } else if *pix {
+                        '#'
+                    } else {
+                        '.'
+                    }
+                })
+                .collect::<String>();
+            len_slice_str.push('\n');
+            len_slice_str
+        })
+        .collect::<String>()
+}
+
+/// Places present `present` into position (`idx_len`, `idx_width`) in `region`.
+fn place_present_in_region_at_pos(
+    present: &PresentShape,
+    region: &Pixels,
+    idx_len: usize,
+    idx_width: usize,
+) -> Result<Pixels, String> {
+    let mut region_copy = region.clone();
+    for ((row, col), &present_pixel) in present.pixels.iter() {
+        let (region_row, region_col) = (idx_len.saturating_add(row), idx_width.saturating_add(col));
+        let Some(region_pixel) = region_copy.get_mut(region_row, region_col) else {
+            return Err(format!(
+                "Could not access position ({region_row}, {region_col}) of region!"
+            ));
+        };
+        if present_pixel {
+            *region_pixel = true;
+        }
+    }
+    Ok(region_copy)
+}
+
+/// Parses input for day 12
+///
+/// # Errors
+///
+/// Returns an error if a region line contains invalid numbers or if the rows of a present shape
+/// differ in length.
+pub fn parse_input(content: &str) -> Result<Vec<TreeRegion>, ParseError> {
+    let error_mapper = |err: regex::Error| {
+        ParseError::in_input(&format!("a valid pattern (internal error: {err:?})"))
+    };
+    let pat_pixels = Regex::new(r"[#\.]+").map_err(error_mapper)?;
+    let pat_region = Regex::new(r"(\d+)x(\d+)\:\s*((?:\d+\s*)+)").map_err(error_mapper)?;
+    let mut shape_rows: Vec<Vec<bool>> = Vec::new();
+    let mut present_shapes: Vec<PresentShape> = Vec::new();
+    let mut regions = Vec::new();
+    for (idx, line) in content.lines().enumerate() {
+        if let Some(match_region) = pat_region.captures(line) {
+            let (_, [width_str, length_str, quantities]) = match_region.extract();
+            let length = parse_token::<usize>(idx, line, length_str, "the length of the region")?;
+            let width = parse_token::<usize>(idx, line, width_str, "the width of the region")?;
+            let shape_quantities = quantities
+                .split_ascii_whitespace()
+                .map(|quantity| parse_token::<usize>(idx, line, quantity, "a number of presents"))
+                .collect::<Result<Vec<usize>, ParseError>>()?;
+            regions.push(TreeRegion {
+                length,
+                present_shapes: present_shapes.clone(),
+                shape_quantities,
+                width,
+            });
+        } else if pat_pixels.is_match(line) {
+            let pixel_line = line.chars().map(|chr| chr == '#').collect::<Vec<bool>>();
+            shape_rows.push(pixel_line);
+        } else {
+            if !shape_rows.is_empty() {
+                let pixels = Grid::from_rows(shape_rows).ok_or_else(|| {
+                    ParseError::in_input("present shapes with rows of equal length")
+                })?;
+                present_shapes.push(PresentShape { pixels });
+            }
+            shape_rows = Vec::new();
+        }
+    }
+    Ok(regions)
+}
+
+/// Solves part 1 by counting all regions that are solvable
+#[must_use]
+pub fn count_solvable(regions: &[TreeRegion]) -> usize {
+    let solvable = regions.iter().map(TreeRegion::fits_all).collect::<Vec<_>>();
+    info!("Solvable states: {solvable:?}");
+    solvable.iter().copied().map(usize::from).sum()
+}
@@ -0,0 +1,49 @@
+//! Tests for placing single present shapes in a region
+
+use aoc_common::grid::Grid;
+use day12::{Pixels, PresentShape, present_fits_in_region_at_pos};
+
+/// Region with a gap in the form of [`u_shape`] at index (0, 1)
+fn region_with_gap() -> Pixels {
+    Grid::from_rows_padded(
+        vec![
+            vec![false, false, false, false],
+            vec![true, false, true, false],
+            vec![true, false, true, false],
+            vec![true, true, true, false],
+        ],
+        &false,
+    )
+}
+
+/// Present in the form of an upside down U
+fn u_shape() -> PresentShape {
+    PresentShape {
+        pixels: Grid::from_rows_padded(
+            vec![
+                vec![true, true, true],
+                vec![true, false, true],
+                vec![true, false, true],
+            ],
+            &false,
+        ),
+    }
+}
+
+/// Checks that a shape fits into a region whose free pixels have exactly the form of the shape
+#[test]
+fn shape_fits_into_matching_gap() {
+    assert!(
+        present_fits_in_region_at_pos(&u_shape(), &region_with_gap(), 0, 1),
+        "Shape should fit here!"
+    );
+}
+
+/// Checks that a shape does not fit if it overlaps occupied pixels
+#[test]
+fn shape_does_not_fit_on_occupied_pixels() {
+    assert!(
+        !present_fits_in_region_at_pos(&u_shape(), &region_with_gap(), 0, 0),
+        "Shape should not fit here!"
+    );
+}